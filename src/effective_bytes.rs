@@ -15,13 +15,15 @@ pub trait EffectiveBytes {
 
 impl<const N: usize> EffectiveBytes for FixedStr<N> {
     fn effective_bytes(&self) -> &[u8] {
-        &self[..self.len()]
+        &self.data[..self.len()]
     }
 }
 
-impl<const N: usize> EffectiveBytes for &FixedStr<N> {
+/// References have the effective bytes of their target, so `&String`, `&FixedStrBuf<N>`,
+/// and the like can be passed wherever an `EffectiveBytes` value is taken.
+impl<T: EffectiveBytes + ?Sized> EffectiveBytes for &T {
     fn effective_bytes(&self) -> &[u8] {
-        (*self).effective_bytes()
+        (**self).effective_bytes()
     }
 }
 
@@ -39,7 +41,7 @@ impl<const N: usize> EffectiveBytes for [u8; N] {
     }
 }
 
-impl EffectiveBytes for &str {
+impl EffectiveBytes for str {
     fn effective_bytes(&self) -> &[u8] {
         self.as_bytes().effective_bytes()
     }
@@ -58,6 +60,9 @@ impl EffectiveBytes for String {
 
 /// An iterator over the effective bytes of a fixed‑capacity string,
 /// stopping at the first null byte.
+///
+/// Created by `into_iter()` or `bytes()` on [`FixedStr`] and [`FixedStrBuf`].
+#[derive(Clone, Debug)]
 pub struct EffectiveBytesIter<const N: usize> {
     pub(super) data: [u8; N],
     pub(super) index: usize,
@@ -76,8 +81,28 @@ impl<const N: usize> Iterator for EffectiveBytesIter<N> {
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
 }
 
+impl<const N: usize> DoubleEndedIterator for EffectiveBytesIter<N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index < self.len {
+            self.len -= 1;
+            Some(self.data[self.len])
+        } else {
+            None
+        }
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for EffectiveBytesIter<N> {}
+
+impl<const N: usize> core::iter::FusedIterator for EffectiveBytesIter<N> {}
+
 //******************************************************************************
 //  Tests
 //******************************************************************************
@@ -4,7 +4,20 @@ use super::*;
 
 /// A builder for incrementally constructing a `FixedStr` with a fixed capacity.
 /// It maintains an internal byte buffer and tracks the number of bytes currently written (the effective length).
-#[derive(Clone, Copy, PartialEq, Eq)]
+/// Like `FixedStr`, its content is always valid UTF‑8.
+///
+/// As with [`FixedStr`], a capacity of zero is rejected at compile time:
+///
+/// ```compile_fail
+/// use fixed_str::FixedStrBuf;
+///
+/// let empty = FixedStrBuf::<0>::default();
+/// ```
+///
+/// Like [`FixedStr`], equality, ordering, and hashing only consider the effective bytes
+/// (up to the first null). Use [`FixedStrBuf::raw_eq`] and [`FixedStrBuf::raw_cmp`] to compare
+/// the full buffers.
+#[derive(Clone, Copy)]
 pub struct FixedStrBuf<const N: usize> {
     pub(super) buffer: [u8; N],
     /// The number of bytes currently stored (i.e. the effective length).
@@ -30,22 +43,28 @@ impl<const N: usize> FixedStrBuf<N> {
     }
 
     /// Creates a new, empty `FixedStrBuf`.
-    ///
-    /// # Panics
-    /// Panics if `N == 0`. Zero‑length strings are not supported.
     pub const fn new() -> Self {
-        panic_on_zero(N);
+        let () = NonZeroCapacity::<N>::ASSERT;
         Self {
             buffer: [0u8; N],
             len: 0,
         }
     }
 
+    /// Returns the string slice representation of the effective content.
+    ///
+    /// The builder only ever holds valid UTF‑8, so this is always the full content.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: Every builder method only writes whole UTF‑8 characters, and conversions
+        // validate their input, so `buffer[..len]` is always valid UTF‑8.
+        unsafe { str::from_utf8_unchecked(&self.buffer[..self.len]) }
+    }
+
     /// Attempts to interpret the current effective bytes (up to the first null) as a valid UTF‑8 string.
     ///
     /// Returns an error if the effective content is not valid UTF‑8.
     pub fn try_as_str(&self) -> Result<&str, FixedStrError> {
-        core::str::from_utf8(self.effective_bytes()).map_err(|_| FixedStrError::InvalidUtf8)
+        Ok(core::str::from_utf8(self.effective_bytes())?)
     }
 
     /// Attempts to append the entire input string to the buffer.
@@ -79,23 +98,59 @@ impl<const N: usize> FixedStrBuf<N> {
 
     /// Appends as many complete UTF‑8 characters from the input string as possible.
     ///
-    /// If the entire string fits into the remaining capacity, it returns `true`.
+    /// Like [`try_push_str`](Self::try_push_str), only the effective bytes of the input
+    /// (up to its first null, if any) are considered.
+    /// If they fit into the remaining capacity, it returns `true`.
     /// Otherwise, it appends only the valid initial segment (up to the last complete character) and returns `false`.
     pub fn push_str_lossy(&mut self, s: &str) -> bool {
-        let remaining = self.remaining();
-        let valid = if s.len() > remaining {
-            truncate_utf8_lossy(s.as_bytes(), remaining)
-        } else {
-            s
-        };
+        !self.push_str_lossy_with_report(s).is_truncated()
+    }
 
-        let bytes = valid.as_bytes();
+    /// Appends like [`push_str_lossy`](Self::push_str_lossy) and reports what was truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStrBuf;
+    ///
+    /// let mut buf = FixedStrBuf::<4>::new();
+    /// buf.try_push_str("ab").unwrap();
+    /// let report = buf.push_str_lossy_with_report("c€");
+    /// assert_eq!(buf.as_str(), "abc");
+    /// assert_eq!(report.dropped_chars, 1);
+    /// assert!(report.incomplete_utf8);
+    /// ```
+    pub fn push_str_lossy_with_report(&mut self, s: &str) -> Truncation {
+        let remaining = self.remaining();
+        let effective = s.effective_bytes();
+        let bytes = truncate_utf8_lossy(effective, remaining).as_bytes();
         if !bytes.is_empty() {
             self.buffer[self.len..self.len + bytes.len()].copy_from_slice(bytes);
             self.len += bytes.len();
         }
+        Truncation::measure(s.as_bytes(), bytes.len(), remaining)
+    }
 
-        bytes.len() == s.len()
+    /// Returns a [`LossyWriter`] that appends formatted output to this buffer,
+    /// keeping as many whole characters as fit instead of failing on overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::fmt::Write;
+    /// use fixed_str::FixedStrBuf;
+    ///
+    /// let mut buf = FixedStrBuf::<8>::new();
+    /// let mut w = buf.lossy_writer();
+    /// write!(w, "{}:{}", "localhost", 8080).unwrap();
+    /// assert!(w.truncated());
+    /// assert_eq!(buf.as_str(), "localhos");
+    /// ```
+    pub fn lossy_writer(&mut self) -> LossyWriter<'_, N> {
+        LossyWriter {
+            buf: self,
+            requested: 0,
+            report: Truncation::default(),
+            nul_position: None,
+        }
     }
 
     /// Finalizes the builder into a `FixedStr`.
@@ -108,17 +163,18 @@ impl<const N: usize> FixedStrBuf<N> {
         FixedStr::from_bytes(self.buffer)
     }
 
-    /// Finalizes the builder into a `FixedStr` without UTF-8 boundary checks.
+    /// Finalizes the builder into a `FixedStr` without re‑validating UTF‑8.
     ///
-    /// # Warning
-    /// Use with care—this may produce values that may cause conversions to panic or comparisons to fail.
+    /// The builder only ever holds valid UTF‑8, so this produces the same result as
+    /// [`FixedStrBuf::finalize`] while skipping the validation pass.
     ///
     /// This method zero‑pads the unused portion of the buffer and creates a `FixedStr`
     /// from the internal byte array. If the written content contains a null byte (`\0`),
     /// the resulting string will terminate at that null, ignoring any bytes that follow.
     pub fn finalize_unsafe(mut self) -> FixedStr<N> {
         self.buffer[self.len..N].fill(0);
-        FixedStr::from_bytes_unsafe(self.buffer)
+        // SAFETY: Every builder method only writes whole UTF‑8 characters.
+        unsafe { FixedStr::from_bytes_unsafe(self.buffer) }
     }
 
     /// Clears the builder, resetting its effective length to zero and zero‑filling the buffer.
@@ -127,17 +183,222 @@ impl<const N: usize> FixedStrBuf<N> {
         self.len = 0;
     }
 
-    /// Truncates the effective content of the buffer to `new_len` bytes.
+    /// Truncates the effective content of the buffer to at most `new_len` bytes.
     ///
-    /// If `new_len` is less than the current effective length, the method zeroes out the removed portion
-    /// and updates the effective length accordingly. If `new_len` is greater than or equal to the current length,
+    /// If `new_len` is less than the current effective length, the content is cut at the last
+    /// character boundary at or before `new_len`, the removed portion is zeroed, and the effective
+    /// length is updated accordingly. If `new_len` is greater than or equal to the current length,
     /// no changes are made.
     pub fn truncate(&mut self, new_len: usize) {
         if new_len < self.len {
-            for i in new_len..self.len {
-                self.buffer[i] = 0;
+            let cut = floor_char_boundary(&self.buffer[..self.len], new_len);
+            self.buffer[cut..self.len].fill(0);
+            self.len = cut;
+        }
+    }
+
+    /// Truncates the effective content of the buffer to exactly `new_len` bytes.
+    ///
+    /// Unlike [`truncate`](Self::truncate), this does not round down when `new_len` falls
+    /// inside a multi‑byte character; the buffer is left unchanged and an error is returned.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::Truncated` with the number of bytes that `truncate(new_len)`
+    /// would have removed if `new_len` is not on a character boundary.
+    pub fn try_truncate(&mut self, new_len: usize) -> Result<(), FixedStrError> {
+        if new_len < self.len {
+            let cut = floor_char_boundary(&self.buffer[..self.len], new_len);
+            if cut != new_len {
+                return Err(FixedStrError::Truncated {
+                    dropped: self.len - cut,
+                });
+            }
+            self.truncate(cut);
+        }
+        Ok(())
+    }
+
+    /// Truncates the effective content of the buffer to at most `n` characters.
+    ///
+    /// If the content has `n` characters or fewer, no changes are made.
+    pub fn truncate_chars(&mut self, n: usize) {
+        if let Some((idx, _)) = self.as_str().char_indices().nth(n) {
+            self.truncate(idx);
+        }
+    }
+
+    /// Compares the full underlying buffers, including any bytes after the effective string.
+    pub fn raw_eq(&self, other: &Self) -> bool {
+        self.buffer == other.buffer
+    }
+
+    /// Orders two builders by their full underlying buffers, including any bytes after the effective string.
+    pub fn raw_cmp(&self, other: &Self) -> Ordering {
+        self.buffer.cmp(&other.buffer)
+    }
+
+    /// Compares the effective string with that of a `FixedStrBuf` or `FixedStr` of any capacity.
+    ///
+    /// See [`FixedStr::eq_fixed`].
+    pub fn eq_fixed<T: EffectiveBytes>(&self, other: &T) -> bool {
+        self.effective_bytes() == other.effective_bytes()
+    }
+
+    /// Orders the effective string against that of a `FixedStrBuf` or `FixedStr` of any capacity.
+    pub fn cmp_fixed<T: EffectiveBytes>(&self, other: &T) -> Ordering {
+        self.effective_bytes().cmp(other.effective_bytes())
+    }
+
+    /// Joins `parts` with `sep` into a new builder, failing instead of truncating.
+    ///
+    /// # Errors
+    /// - Returns `FixedStrError::Overflow` if the joined string is longer than `N` bytes,
+    ///   with `found` set to its full length.
+    /// - Returns `FixedStrError::EmbeddedNull` if `sep` contains a null byte.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::{FixedStr, FixedStrBuf};
+    ///
+    /// let fields = [FixedStr::<8>::new("GPGGA"), FixedStr::new("123519")];
+    /// let frame = FixedStrBuf::<16>::join(&fields, ",").unwrap();
+    /// assert_eq!(frame.as_str(), "GPGGA,123519");
+    /// assert!(FixedStrBuf::<8>::join(&fields, ",").is_err());
+    /// ```
+    pub fn join<const M: usize>(parts: &[FixedStr<M>], sep: &str) -> Result<Self, FixedStrError> {
+        let mut buf = Self::new();
+        let mut writer = buf.lossy_writer();
+        Self::write_joined(&mut writer, parts, sep);
+        if writer.requested() > N {
+            return Err(FixedStrError::Overflow {
+                available: N,
+                found: writer.requested(),
+            });
+        }
+        if let Some(position) = writer.nul_position() {
+            return Err(FixedStrError::EmbeddedNull { position });
+        }
+        Ok(buf)
+    }
+
+    /// Joins `parts` with `sep` into a new builder, keeping as many whole characters as fit.
+    ///
+    /// A null byte in `sep` ends the content, as with [`push_str_lossy`](Self::push_str_lossy).
+    pub fn join_lossy<const M: usize>(parts: &[FixedStr<M>], sep: &str) -> Self {
+        let mut buf = Self::new();
+        Self::write_joined(&mut buf.lossy_writer(), parts, sep);
+        buf
+    }
+
+    fn write_joined<const M: usize>(
+        writer: &mut LossyWriter<'_, N>,
+        parts: &[FixedStr<M>],
+        sep: &str,
+    ) {
+        for (i, part) in parts.iter().enumerate() {
+            if i > 0 {
+                let _ = writer.write_str(sep);
+            }
+            let _ = writer.write_str(part.as_str());
+        }
+    }
+
+    /// Copies the content into a builder of capacity `M`, truncating at the last
+    /// UTF‑8 boundary if it does not fit.
+    pub fn resize<const M: usize>(&self) -> FixedStrBuf<M> {
+        let mut buf = FixedStrBuf::new();
+        buf.push_str_lossy(self.as_str());
+        buf
+    }
+
+    /// Copies the content into a builder of capacity `M`.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::Overflow` if the content is longer than `M` bytes.
+    pub fn try_resize<const M: usize>(&self) -> Result<FixedStrBuf<M>, FixedStrError> {
+        let mut buf = FixedStrBuf::new();
+        buf.try_push_str(self.as_str())?;
+        Ok(buf)
+    }
+
+    /// Copies the content into a builder of capacity `M >= N`, which always fits.
+    ///
+    /// Using a smaller `M` is rejected at compile time:
+    ///
+    /// ```compile_fail
+    /// use fixed_str::FixedStrBuf;
+    ///
+    /// let narrow: FixedStrBuf<4> = FixedStrBuf::<8>::new().widen();
+    /// ```
+    pub fn widen<const M: usize>(&self) -> FixedStrBuf<M> {
+        let () = AtLeastCapacity::<N, M>::ASSERT;
+        let mut buffer = [0u8; M];
+        buffer[..self.len].copy_from_slice(&self.buffer[..self.len]);
+        FixedStrBuf {
+            buffer,
+            len: self.len,
+        }
+    }
+
+    /// Collects the items of an iterator into a new builder, failing instead of truncating.
+    ///
+    /// Each item is appended through its `Display` implementation, so `char`, `&str`, and
+    /// `String` items all work.
+    ///
+    /// # Errors
+    /// - Returns `FixedStrError::Overflow` if the items are longer than N bytes in total,
+    ///   with `found` set to the full length. The whole iterator is consumed to count it.
+    /// - Returns `FixedStrError::EmbeddedNull` if the items contain a null byte.
+    /// - Returns `FixedStrError::Format` if the `Display` implementation of an item fails.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::{FixedStrBuf, FixedStrError};
+    ///
+    /// let digits = FixedStrBuf::<4>::try_from_iter("a1b2c3".chars().filter(char::is_ascii_digit));
+    /// assert_eq!(digits.unwrap().as_str(), "123");
+    ///
+    /// let err = FixedStrBuf::<4>::try_from_iter(["ab", "cd", "ef"]).unwrap_err();
+    /// assert_eq!(err, FixedStrError::Overflow { available: 4, found: 6 });
+    /// ```
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, FixedStrError>
+    where
+        I: IntoIterator,
+        I::Item: fmt::Display,
+    {
+        let mut buf = Self::new();
+        let mut writer = buf.lossy_writer();
+        for item in iter {
+            // `LossyWriter` never fails, so an error can only come from the item itself.
+            if write!(writer, "{}", item).is_err() {
+                return Err(FixedStrError::Format);
+            }
+        }
+        if writer.requested() > N {
+            return Err(FixedStrError::Overflow {
+                available: N,
+                found: writer.requested(),
+            });
+        }
+        if let Some(position) = writer.nul_position() {
+            return Err(FixedStrError::EmbeddedNull { position });
+        }
+        Ok(buf)
+    }
+
+    /// Appends items through a [`LossyWriter`] until one is cut off or a null byte ends the text.
+    fn extend_lossy<I>(&mut self, iter: I)
+    where
+        I: IntoIterator,
+        I::Item: fmt::Display,
+    {
+        let mut writer = self.lossy_writer();
+        for item in iter {
+            // `LossyWriter` never fails; an error could only come from the item itself.
+            let _ = write!(writer, "{}", item);
+            if writer.truncated() || writer.nul_position().is_some() {
+                break;
             }
-            self.len = new_len;
         }
     }
 
@@ -174,10 +435,94 @@ impl<const N: usize> fmt::Debug for FixedStrBuf<N> {
     }
 }
 
+/// Appends formatted output with the same atomic semantics as [`FixedStrBuf::try_push_str`].
+///
+/// If a fragment does not fit, nothing of it is written and `fmt::Error` is returned,
+/// leaving the buffer at the end of the last complete write. A fragment containing a null
+/// byte (`\0`) is refused the same way, since the null would end the string and cut off
+/// the rest of the output.
+///
+/// # Examples
+/// ```
+/// use core::fmt::Write;
+/// use fixed_str::FixedStrBuf;
+///
+/// let mut buf = FixedStrBuf::<16>::new();
+/// write!(buf, "{}:{}", "host", 8080).unwrap();
+/// assert_eq!(buf.as_str(), "host:8080");
+/// ```
+impl<const N: usize> fmt::Write for FixedStrBuf<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        check_no_null(s).map_err(|_| fmt::Error)?;
+        self.try_push_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+/// A `fmt::Write` adapter over a [`FixedStrBuf`] that never fails.
+///
+/// Output is appended until the first fragment that does not fit; that fragment is cut at
+/// the last whole character and everything after it is dropped, so the buffer always holds
+/// a prefix of the formatted text. A null byte (`\0`) in the output likewise ends it.
+/// Created by [`FixedStrBuf::lossy_writer`].
+pub struct LossyWriter<'a, const N: usize> {
+    buf: &'a mut FixedStrBuf<N>,
+    requested: usize,
+    report: Truncation,
+    nul_position: Option<usize>,
+}
+
+impl<const N: usize> LossyWriter<'_, N> {
+    /// Returns `true` if any output was dropped because the buffer was full.
+    pub fn truncated(&self) -> bool {
+        self.report.is_truncated()
+    }
+
+    /// Returns a [`Truncation`] report covering everything written to this adapter so far.
+    ///
+    /// Output after the first null byte is not counted as dropped.
+    pub fn report(&self) -> Truncation {
+        self.report
+    }
+
+    /// Returns the total number of bytes written to this adapter, including any that were dropped.
+    pub fn requested(&self) -> usize {
+        self.requested
+    }
+
+    /// Returns the offset of the first null byte in the output written to this adapter, if any.
+    pub fn nul_position(&self) -> Option<usize> {
+        self.nul_position
+    }
+}
+
+impl<const N: usize> fmt::Write for LossyWriter<'_, N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let offset = self.requested;
+        self.requested += s.len();
+        if self.nul_position.is_some() {
+            return Ok(());
+        }
+        let report = if self.report.is_truncated() {
+            // Once a fragment has been cut, later fragments are dropped entirely.
+            Truncation::measure(s.as_bytes(), 0, 0)
+        } else {
+            self.buf.push_str_lossy_with_report(s)
+        };
+        if report.stopped_at_nul {
+            self.nul_position = Some(offset + s.effective_bytes().len());
+        }
+        self.report.dropped_bytes += report.dropped_bytes;
+        self.report.dropped_chars += report.dropped_chars;
+        self.report.stopped_at_nul |= report.stopped_at_nul;
+        self.report.incomplete_utf8 |= report.incomplete_utf8;
+        Ok(())
+    }
+}
+
 impl<const N: usize> EffectiveBytes for FixedStrBuf<N> {
-    /// Returns the effective bytes (up to the first null byte) from the internal buffer.
+    /// Returns the bytes written so far; the builder never stores a null byte in them.
     fn effective_bytes(&self) -> &[u8] {
-        self.buffer.effective_bytes()
+        &self.buffer[..self.len]
     }
 }
 
@@ -187,12 +532,16 @@ impl<const N: usize> AsRef<[u8]> for FixedStrBuf<N> {
     }
 }
 
+/// Borrows the effective string; `Hash` matches `str`, so builders can be looked up by `&str`.
+impl<const N: usize> Borrow<str> for FixedStrBuf<N> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
 impl<const N: usize> Default for FixedStrBuf<N> {
     fn default() -> Self {
-        Self {
-            buffer: [0; N],
-            len: 0,
-        }
+        Self::new()
     }
 }
 
@@ -206,31 +555,31 @@ impl<const N: usize> core::ops::Deref for FixedStrBuf<N> {
 /// Creates a `FixedStrBuf` from a `FixedStr`.
 ///
 /// The effective length of the builder is taken from the `FixedStr` (i.e. the number
-/// of valid bytes up to the first null).
-///
-/// # Panics
-/// Panics if `N == 0`. Zero‑length strings are not supported.
+/// of valid bytes up to the first null), and any bytes after the null are zeroed.
 impl<const N: usize> From<FixedStr<N>> for FixedStrBuf<N> {
     fn from(fixed: FixedStr<N>) -> Self {
-        Self {
-            buffer: fixed.data,
-            len: fixed.len(),
-        }
+        let len = fixed.len();
+        let mut buffer = fixed.data;
+        buffer[len..].fill(0);
+        Self { buffer, len }
     }
 }
 
 /// Attempts to create a `FixedStrBuf` from a byte slice using exact copy semantics.
 ///
 /// The method uses `BufferCopyMode::Exact` and computes the effective length by finding
-/// the first null byte in the copied buffer.
+/// the first null byte in the copied buffer. Any bytes after that null are zeroed.
 ///
-/// # Panics
-/// Panics if `N == 0`. Zero‑length strings are not supported.
+/// # Errors
+/// - Returns `FixedStrError::Overflow` if the slice is longer than N bytes.
+/// - Returns `FixedStrError::InvalidUtf8` if the bytes before the first null are not valid UTF‑8.
 impl<const N: usize> core::convert::TryFrom<&[u8]> for FixedStrBuf<N> {
     type Error = FixedStrError;
     fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
-        let buf = copy_into_buffer(slice, BufferCopyMode::Exact)?;
+        let mut buf = copy_into_buffer(slice, BufferCopyMode::Exact)?;
         let effective_len = find_first_null(&buf);
+        str::from_utf8(&buf[..effective_len])?;
+        buf[effective_len..].fill(0);
         Ok(Self {
             buffer: buf,
             len: effective_len,
@@ -238,22 +587,120 @@ impl<const N: usize> core::convert::TryFrom<&[u8]> for FixedStrBuf<N> {
     }
 }
 
+/// Strictly creates a `FixedStrBuf` from a string slice.
+///
+/// # Errors
+/// - Returns `FixedStrError::Overflow` if the input is longer than N bytes.
+/// - Returns `FixedStrError::EmbeddedNull` if the input contains a null byte.
+impl<const N: usize> core::convert::TryFrom<&str> for FixedStrBuf<N> {
+    type Error = FixedStrError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        check_no_null(s)?;
+        let mut buf = Self::new();
+        buf.try_push_str(s)?;
+        Ok(buf)
+    }
+}
+
+/// Parses a `FixedStrBuf` with the same strict rules as `TryFrom<&str>`.
+impl<const N: usize> core::str::FromStr for FixedStrBuf<N> {
+    type Err = FixedStrError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+/// Appends characters with the truncating semantics of [`FixedStrBuf::lossy_writer`].
+///
+/// Iteration stops at the first character that does not fit or at the first null character,
+/// so the buffer always holds a prefix of the text.
+///
+/// # Examples
+/// ```
+/// use fixed_str::FixedStrBuf;
+///
+/// let mut buf = FixedStrBuf::<4>::new();
+/// buf.extend("a-b-c".chars().filter(|&c| c != '-'));
+/// buf.extend(['d', 'e']);
+/// assert_eq!(buf.as_str(), "abcd");
+/// ```
+impl<const N: usize> Extend<char> for FixedStrBuf<N> {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+        self.extend_lossy(iter);
+    }
+}
+
+impl<'a, const N: usize> Extend<&'a char> for FixedStrBuf<N> {
+    fn extend<I: IntoIterator<Item = &'a char>>(&mut self, iter: I) {
+        self.extend_lossy(iter);
+    }
+}
+
+/// Appends string slices with the same truncating semantics as `Extend<char>`.
+impl<'a, const N: usize> Extend<&'a str> for FixedStrBuf<N> {
+    fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+        self.extend_lossy(iter);
+    }
+}
+
+/// Collects characters, truncating like `Extend<char>`. Use [`FixedStrBuf::try_from_iter`]
+/// to fail on overflow instead.
+impl<const N: usize> FromIterator<char> for FixedStrBuf<N> {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut buf = Self::new();
+        buf.extend(iter);
+        buf
+    }
+}
+
+/// Collects string slices, truncating like `Extend<&str>`. Use [`FixedStrBuf::try_from_iter`]
+/// to fail on overflow instead.
+impl<'a, const N: usize> FromIterator<&'a str> for FixedStrBuf<N> {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut buf = Self::new();
+        buf.extend(iter);
+        buf
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const N: usize> TryFrom<String> for FixedStrBuf<N> {
+    type Error = FixedStrError;
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::try_from(s.as_str())
+    }
+}
+
 impl<const N: usize> Hash for FixedStrBuf<N> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        // Only hash the effective bytes (up to the first null) to represent the visible string.
-        self.effective_bytes().hash(state);
+        // Hash like `str` so that lookups through `Borrow<str>` and mixed `FixedStr` keys agree.
+        self.as_str().hash(state);
     }
 }
 
+/// Iterates over the effective bytes, like `IntoIterator` for [`FixedStr`].
 impl<const N: usize> IntoIterator for FixedStrBuf<N> {
     type Item = u8;
-    type IntoIter = core::array::IntoIter<u8, N>;
+    type IntoIter = EffectiveBytesIter<N>;
 
     fn into_iter(self) -> Self::IntoIter {
-        core::array::IntoIter::into_iter(self.buffer.into_iter())
+        EffectiveBytesIter {
+            data: self.buffer,
+            index: 0,
+            len: self.effective_bytes().len(),
+        }
     }
 }
 
+impl<const N: usize> PartialEq for FixedStrBuf<N> {
+    fn eq(&self, other: &Self) -> bool {
+        // Compare only the effective bytes, consistent with `Ord` and `Hash`.
+        self.effective_bytes() == other.effective_bytes()
+    }
+}
+
+impl<const N: usize> Eq for FixedStrBuf<N> {}
+
 impl<const N: usize> Ord for FixedStrBuf<N> {
     fn cmp(&self, other: &Self) -> Ordering {
         // Compare only the effective bytes (up to the first null) of each builder.
@@ -373,6 +820,24 @@ mod buffer_tests {
         assert_eq!(fixed.as_str(), "Hello");
     }
 
+    #[test]
+    fn test_push_str_lossy_with_report() {
+        let mut buf = FixedStrBuf::<6>::new();
+        // Only the bytes before the null are appended, and the null is not truncation.
+        let report = buf.push_str_lossy_with_report("ab\0cd");
+        assert_eq!(buf.len(), 2);
+        assert!(report.stopped_at_nul);
+        assert!(!report.is_truncated());
+
+        // "c" and "d" fit; the 4-byte emoji is dropped whole.
+        let report = buf.push_str_lossy_with_report("cd😊e");
+        assert_eq!(buf.as_str(), "abcd");
+        assert_eq!(report.dropped_bytes, 5);
+        assert_eq!(report.dropped_chars, 2);
+        assert!(report.incomplete_utf8);
+        assert!(!buf.push_str_lossy("x😊"));
+    }
+
     #[test]
     fn test_finalize_trailing_zeros() {
         let mut buf = FixedStrBuf::<10>::new();
@@ -407,6 +872,16 @@ mod buffer_tests {
         // Since the input is longer than the buffer capacity, this should error.
         let result = FixedStrBuf::<5>::try_from(&input[..]);
         assert!(result.is_err());
+
+        // Invalid UTF-8 before the first null is rejected.
+        let result = FixedStrBuf::<5>::try_from(&b"a\xFFb"[..]);
+        assert_eq!(
+            result,
+            Err(FixedStrError::InvalidUtf8 {
+                valid_up_to: 1,
+                error_len: Some(1),
+            })
+        );
     }
 
     #[test]
@@ -423,6 +898,25 @@ mod buffer_tests {
         assert_eq!(buf1, buf3);
     }
 
+    #[test]
+    fn test_from_fixed_str_zeroes_trailing_bytes() {
+        // SAFETY: The bytes before the first null are ASCII.
+        let a: FixedStrBuf<5> = unsafe { FixedStr::<5>::from_bytes_unsafe(*b"ab\0\xFFx") }.into();
+        let mut b = FixedStrBuf::<5>::new();
+        b.try_push_str("ab").unwrap();
+        assert!(a.raw_eq(&b));
+
+        let mut a = a;
+        a.try_push_str("c").unwrap();
+        b.try_push_str("c").unwrap();
+        assert_eq!(a.try_as_str(), Ok("abc"));
+        assert_eq!(a, b);
+        assert!(a.bytes().eq(*b"abc"));
+
+        let c = FixedStrBuf::<5>::try_from(&b"ab\0\xFFx"[..]).unwrap();
+        assert!(c.raw_eq(&FixedStrBuf::try_from("ab").unwrap()));
+    }
+
     #[test]
     fn test_truncate_reduces_length() {
         let mut buf = FixedStrBuf::<10>::new();
@@ -451,6 +945,97 @@ mod buffer_tests {
         assert_eq!(buf.len(), 2);
     }
 
+    #[test]
+    fn test_truncate_respects_char_boundaries() {
+        let mut buf = FixedStrBuf::<10>::new();
+        buf.try_push_str("añb😊").unwrap();
+        // Index 2 falls inside 'ñ'; the cut moves back to 1.
+        buf.truncate(2);
+        assert_eq!(buf.len(), 1);
+        assert_eq!(buf.as_str(), "a");
+        assert!(buf.try_as_str().is_ok());
+
+        let mut buf = FixedStrBuf::<10>::new();
+        buf.try_push_str("añb😊").unwrap();
+        buf.truncate_chars(3);
+        assert_eq!(buf.as_str(), "añb");
+        buf.truncate_chars(5);
+        assert_eq!(buf.as_str(), "añb");
+    }
+
+    #[test]
+    fn test_resize_between_capacities() {
+        let mut buf = FixedStrBuf::<6>::new();
+        buf.try_push_str("héllo").unwrap();
+        let small = buf.resize::<2>();
+        assert_eq!(small.as_str(), "h");
+        assert_eq!(small.len(), 1);
+        assert!(buf.try_resize::<5>().is_err());
+        let mut wide: FixedStrBuf<10> = buf.widen();
+        assert_eq!(wide.len(), 6);
+        wide.try_push_str("!!!").unwrap();
+        assert_eq!(wide.as_str(), "héllo!!!");
+    }
+
+    #[test]
+    fn test_cross_capacity_comparisons() {
+        let mut a = FixedStrBuf::<32>::new();
+        a.try_push_str("name").unwrap();
+        let mut b = FixedStrBuf::<8>::new();
+        b.try_push_str("name").unwrap();
+        assert!(a.eq_fixed(&b));
+        assert!(a.eq_fixed(&FixedStr::<4>::new("name")));
+        assert!(FixedStr::<16>::new("name").eq_fixed(&b));
+        b.try_push_str("s").unwrap();
+        assert_eq!(a.cmp_fixed(&b), Ordering::Less);
+    }
+
+    #[test]
+    fn test_join_strict_and_lossy() {
+        let parts = [
+            FixedStr::<4>::new("ab"),
+            FixedStr::new(""),
+            FixedStr::new("cd"),
+        ];
+        assert_eq!(
+            FixedStrBuf::<8>::join(&parts, ", ").unwrap().as_str(),
+            "ab, , cd"
+        );
+        assert_eq!(
+            FixedStrBuf::<6>::join(&parts, ", "),
+            Err(FixedStrError::Overflow {
+                available: 6,
+                found: 8
+            })
+        );
+        assert_eq!(
+            FixedStrBuf::<6>::join_lossy(&parts, ", ").as_str(),
+            "ab, , "
+        );
+        assert_eq!(
+            FixedStrBuf::<8>::join(&parts, "\0"),
+            Err(FixedStrError::EmbeddedNull { position: 2 })
+        );
+        let empty: [FixedStr<4>; 0] = [];
+        assert!(FixedStrBuf::<4>::join(&empty, ",").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_try_truncate_reports_split_char() {
+        let mut buf = FixedStrBuf::<10>::new();
+        buf.try_push_str("añb").unwrap();
+        // Index 2 falls inside 'ñ'; nothing changes.
+        assert_eq!(
+            buf.try_truncate(2),
+            Err(FixedStrError::Truncated { dropped: 3 })
+        );
+        assert_eq!(buf.as_str(), "añb");
+        assert_eq!(buf.try_truncate(3), Ok(()));
+        assert_eq!(buf.as_str(), "añ");
+        assert_eq!(buf.try_truncate(10), Ok(()));
+        assert_eq!(buf.as_str(), "añ");
+    }
+
     #[test]
     fn test_from_fixedstr_effective_length() {
         // Create a FixedStr with capacity 10 from a string that doesn't fill it.
@@ -473,13 +1058,102 @@ mod buffer_tests {
         assert_eq!(buf.effective_bytes(), b"Hello");
     }
 
+    #[test]
+    fn test_from_str_strict() {
+        let buf: FixedStrBuf<8> = "abc".parse().unwrap();
+        assert_eq!(buf.len(), 3);
+        assert_eq!(buf.as_str(), "abc");
+        assert!(matches!(
+            "abcdefghi".parse::<FixedStrBuf<8>>(),
+            Err(FixedStrError::Overflow { .. })
+        ));
+        assert!(matches!(
+            FixedStrBuf::<8>::try_from("a\0b"),
+            Err(FixedStrError::EmbeddedNull { position: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_fmt_write_keeps_last_complete_write() {
+        use core::fmt::Write;
+        let (head, tail) = ("abc", "defgh");
+        let mut buf = FixedStrBuf::<8>::new();
+        assert!(write!(buf, "{}-{}", head, tail).is_err());
+        // "abc" and "-" fit, "defgh" does not and is not written at all.
+        assert_eq!(buf.as_str(), "abc-");
+    }
+
+    #[test]
+    fn test_fmt_write_rejects_null() {
+        use core::fmt::Write;
+        let (head, tail) = ("a", "b\0c");
+        let mut buf = FixedStrBuf::<8>::new();
+        assert!(write!(buf, "{}{}", head, tail).is_err());
+        assert_eq!(buf.as_str(), "a");
+        assert!(buf.raw_eq(&FixedStrBuf::try_from("a").unwrap()));
+    }
+
+    #[test]
+    fn test_lossy_writer_keeps_whole_chars() {
+        use core::fmt::Write;
+        let parts = ["ab", "cdé", "f"];
+        let mut buf = FixedStrBuf::<5>::new();
+        let mut w = buf.lossy_writer();
+        write!(w, "{}{}{}", parts[0], parts[1], parts[2]).unwrap();
+        assert!(w.truncated());
+        assert_eq!(w.requested(), 7);
+        // "é" would straddle the capacity and is dropped; "f" is never appended after the gap.
+        assert_eq!(buf.as_str(), "abcd");
+    }
+
+    #[test]
+    fn test_lossy_writer_stops_at_null() {
+        use core::fmt::Write;
+        let parts = ["a\0b", "c"];
+        let mut buf = FixedStrBuf::<8>::new();
+        let mut w = buf.lossy_writer();
+        write!(w, "{}{}", parts[0], parts[1]).unwrap();
+        assert_eq!(w.nul_position(), Some(1));
+        assert!(!w.truncated());
+        assert_eq!(buf.as_str(), "a");
+        assert_eq!(buf.len(), 1);
+    }
+
+    #[test]
+    fn test_extend_stops_at_first_cut() {
+        let mut buf = FixedStrBuf::<4>::new();
+        buf.extend(["ab", "c€", "d"]);
+        assert_eq!(buf.as_str(), "abc");
+        buf.extend(&['x', '\0', 'y']);
+        assert_eq!(buf.as_str(), "abcx");
+
+        let buf: FixedStrBuf<8> = ['a', '\0', 'b'].into_iter().collect();
+        assert_eq!(buf.as_str(), "a");
+    }
+
+    #[test]
+    fn test_try_from_iter() {
+        let buf = FixedStrBuf::<8>::try_from_iter(["key", "=", "val"]).unwrap();
+        assert_eq!(buf.as_str(), "key=val");
+        assert_eq!(
+            FixedStrBuf::<8>::try_from_iter("ab\0c".chars()),
+            Err(FixedStrError::EmbeddedNull { position: 2 })
+        );
+        assert_eq!(
+            FixedStrBuf::<2>::try_from_iter(['é', 'é']),
+            Err(FixedStrError::Overflow {
+                available: 2,
+                found: 4,
+            })
+        );
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn test_fixed_str_buf_into_iter() {
         let mut buf = FixedStrBuf::<5>::new();
         buf.try_push_str("Hey").unwrap();
         let bytes: Vec<u8> = buf.into_iter().collect();
-        assert_eq!(bytes[..3], *b"Hey");
-        assert_eq!(bytes[3..], [0u8; 2]);
+        assert_eq!(bytes, b"Hey");
     }
 }
@@ -0,0 +1,412 @@
+// fixed_str/src/fs_ci.rs
+
+use super::*;
+use core::marker::PhantomData;
+
+/// Decides which characters a [`FixedStrCi`] or [`CiStr`] treats as equal.
+///
+/// The crate provides [`AsciiFold`] and [`SimpleFold`]. Each character is folded on its own,
+/// so two strings are equal exactly when they have the same length in characters and every
+/// pair of characters folds to the same value.
+pub trait CaseFold {
+    /// Maps a character to the representative of its case class.
+    fn fold(c: char) -> char;
+}
+
+/// Folds ASCII letters only; all other characters must match exactly.
+///
+/// This is the right choice for protocol identifiers such as HTTP header names.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AsciiFold;
+
+impl CaseFold for AsciiFold {
+    fn fold(c: char) -> char {
+        c.to_ascii_lowercase()
+    }
+}
+
+/// Folds with a character‑by‑character approximation of Unicode simple case folding.
+///
+/// Each character is mapped to the lower case of its upper case, so `'Σ'`, `'σ'`, and `'ς'`
+/// all match. A character whose upper case expands to several characters, such as `'ß'`
+/// (upper case `"SS"`), does not match that expansion.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SimpleFold;
+
+impl CaseFold for SimpleFold {
+    fn fold(c: char) -> char {
+        single(c.to_uppercase())
+            .and_then(|upper| single(upper.to_lowercase()))
+            .or_else(|| single(c.to_lowercase()))
+            .unwrap_or(c)
+    }
+}
+
+/// Returns the only character of `chars`, or `None` if it has zero or several.
+fn single(mut chars: impl Iterator<Item = char>) -> Option<char> {
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+//******************************************************************************
+//  CiStr
+//******************************************************************************
+
+/// A borrowed string slice that compares, orders, and hashes with case folding `F`.
+///
+/// `FixedStrCi` borrows as `CiStr`, so maps keyed by `FixedStrCi` can be queried with a plain
+/// `&str` wrapped in [`CiStr::new`].
+#[repr(transparent)]
+pub struct CiStr<F: CaseFold = AsciiFold> {
+    _fold: PhantomData<F>,
+    text: str,
+}
+
+impl<F: CaseFold> CiStr<F> {
+    /// Wraps a string slice for case‑insensitive comparison.
+    pub fn new(text: &str) -> &Self {
+        // SAFETY: `CiStr` is a `repr(transparent)` wrapper around `str`.
+        unsafe { &*(text as *const str as *const Self) }
+    }
+
+    /// Returns the original text.
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the folded characters of the text.
+    fn folded(&self) -> impl Iterator<Item = char> + '_ {
+        self.text.chars().map(F::fold)
+    }
+}
+
+impl<F: CaseFold> fmt::Debug for CiStr<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.text, f)
+    }
+}
+
+impl<F: CaseFold> fmt::Display for CiStr<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.text, f)
+    }
+}
+
+impl<F: CaseFold> Hash for CiStr<F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for c in self.folded() {
+            state.write_u32(c as u32);
+        }
+        // Terminate like `str` so that adjacent strings in a tuple hash differently.
+        state.write_u8(0xff);
+    }
+}
+
+impl<F: CaseFold> PartialEq for CiStr<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.folded().eq(other.folded())
+    }
+}
+
+impl<F: CaseFold> Eq for CiStr<F> {}
+
+impl<F: CaseFold> Ord for CiStr<F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.folded().cmp(other.folded())
+    }
+}
+
+impl<F: CaseFold> PartialOrd for CiStr<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+//******************************************************************************
+//  FixedStrCi
+//******************************************************************************
+
+/// A `FixedStr` key that keeps its original text but compares, orders, and hashes with case
+/// folding `F`.
+///
+/// Every comparison of a `FixedStrCi`, including with `&str` and `FixedStr`, folds case.
+/// The default [`AsciiFold`] suits protocol identifiers; [`SimpleFold`] also folds non‑ASCII
+/// letters.
+///
+/// # Examples
+/// ```
+/// use std::collections::HashMap;
+/// use fixed_str::{CiStr, FixedStrCi};
+///
+/// let mut headers: HashMap<FixedStrCi<16>, u32> = HashMap::new();
+/// headers.insert(FixedStrCi::new("Content-Length"), 42);
+///
+/// assert_eq!(headers.get(CiStr::new("content-length")), Some(&42));
+/// let (key, _) = headers.iter().next().unwrap();
+/// assert_eq!(key.as_str(), "Content-Length");
+/// assert_eq!(*key, "CONTENT-LENGTH");
+/// ```
+#[repr(transparent)]
+pub struct FixedStrCi<const N: usize, F: CaseFold = AsciiFold> {
+    inner: FixedStr<N>,
+    _fold: PhantomData<F>,
+}
+
+impl<const N: usize, F: CaseFold> FixedStrCi<N, F> {
+    /// Creates a key from `input`, truncating like [`FixedStr::new`].
+    pub fn new(input: &str) -> Self {
+        Self::from_fixed(FixedStr::new(input))
+    }
+
+    /// Wraps an existing `FixedStr`.
+    pub const fn from_fixed(inner: FixedStr<N>) -> Self {
+        Self {
+            inner,
+            _fold: PhantomData,
+        }
+    }
+
+    /// Returns the wrapped `FixedStr`.
+    pub const fn as_fixed_str(&self) -> &FixedStr<N> {
+        &self.inner
+    }
+
+    /// Consumes the key and returns the wrapped `FixedStr`.
+    pub const fn into_inner(self) -> FixedStr<N> {
+        self.inner
+    }
+
+    /// Returns the text as a [`CiStr`] with the same case folding.
+    pub fn as_ci_str(&self) -> &CiStr<F> {
+        CiStr::new(self.inner.as_str())
+    }
+}
+
+/// ASCII case‑insensitive comparison with any capacity.
+impl<const N: usize> FixedStr<N> {
+    /// Returns `true` if the effective strings are equal, ignoring ASCII case.
+    ///
+    /// This accepts a string, or another `FixedStr` or `FixedStrBuf` of any capacity, by value
+    /// or by reference, and never looks at the padding, unlike the slice method reached
+    /// through `Deref`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let call = FixedStr::<8>::new("DL1ABC");
+    /// assert!(call.eq_ignore_ascii_case(FixedStr::<6>::new("dl1abc")));
+    /// assert!(call.eq_ignore_ascii_case("Dl1Abc"));
+    /// ```
+    pub fn eq_ignore_ascii_case<T: EffectiveBytes>(&self, other: T) -> bool {
+        self.effective_bytes()
+            .eq_ignore_ascii_case(other.effective_bytes())
+    }
+}
+
+impl<const N: usize> FixedStrBuf<N> {
+    /// Returns `true` if the effective strings are equal, ignoring ASCII case.
+    ///
+    /// See [`FixedStr::eq_ignore_ascii_case`].
+    pub fn eq_ignore_ascii_case<T: EffectiveBytes>(&self, other: T) -> bool {
+        self.effective_bytes()
+            .eq_ignore_ascii_case(other.effective_bytes())
+    }
+}
+
+//******************************************************************************
+//  Implementations
+//******************************************************************************
+
+impl<const N: usize, F: CaseFold> Clone for FixedStrCi<N, F> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const N: usize, F: CaseFold> Copy for FixedStrCi<N, F> {}
+
+impl<const N: usize, F: CaseFold> fmt::Debug for FixedStrCi<N, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl<const N: usize, F: CaseFold> fmt::Display for FixedStrCi<N, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl<const N: usize, F: CaseFold> Default for FixedStrCi<N, F> {
+    fn default() -> Self {
+        Self::from_fixed(FixedStr::default())
+    }
+}
+
+/// Gives read access to the wrapped `FixedStr`. Its own comparisons remain case‑sensitive.
+impl<const N: usize, F: CaseFold> core::ops::Deref for FixedStrCi<N, F> {
+    type Target = FixedStr<N>;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<const N: usize, F: CaseFold> AsRef<str> for FixedStrCi<N, F> {
+    fn as_ref(&self) -> &str {
+        self.inner.as_str()
+    }
+}
+
+/// Borrows as a [`CiStr`], whose `Hash` and `Eq` fold case the same way.
+impl<const N: usize, F: CaseFold> Borrow<CiStr<F>> for FixedStrCi<N, F> {
+    fn borrow(&self) -> &CiStr<F> {
+        self.as_ci_str()
+    }
+}
+
+impl<const N: usize, F: CaseFold> From<FixedStr<N>> for FixedStrCi<N, F> {
+    fn from(inner: FixedStr<N>) -> Self {
+        Self::from_fixed(inner)
+    }
+}
+
+impl<const N: usize, F: CaseFold> From<FixedStrCi<N, F>> for FixedStr<N> {
+    fn from(key: FixedStrCi<N, F>) -> Self {
+        key.inner
+    }
+}
+
+/// Strictly creates a key with the same rules as `FixedStr`'s `TryFrom<&str>`.
+impl<const N: usize, F: CaseFold> core::convert::TryFrom<&str> for FixedStrCi<N, F> {
+    type Error = FixedStrError;
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        FixedStr::try_from(input).map(Self::from_fixed)
+    }
+}
+
+/// Parses a key with the same strict rules as `TryFrom<&str>`.
+impl<const N: usize, F: CaseFold> core::str::FromStr for FixedStrCi<N, F> {
+    type Err = FixedStrError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::try_from(input)
+    }
+}
+
+impl<const N: usize, F: CaseFold> Hash for FixedStrCi<N, F> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Hash like `CiStr` so that lookups through `Borrow<CiStr<F>>` agree.
+        self.as_ci_str().hash(state);
+    }
+}
+
+impl<const N: usize, const M: usize, F: CaseFold> PartialEq<FixedStrCi<M, F>> for FixedStrCi<N, F> {
+    fn eq(&self, other: &FixedStrCi<M, F>) -> bool {
+        self.as_ci_str() == other.as_ci_str()
+    }
+}
+
+impl<const N: usize, F: CaseFold> Eq for FixedStrCi<N, F> {}
+
+impl<const N: usize, F: CaseFold> Ord for FixedStrCi<N, F> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_ci_str().cmp(other.as_ci_str())
+    }
+}
+
+impl<const N: usize, const M: usize, F: CaseFold> PartialOrd<FixedStrCi<M, F>>
+    for FixedStrCi<N, F>
+{
+    fn partial_cmp(&self, other: &FixedStrCi<M, F>) -> Option<Ordering> {
+        Some(self.as_ci_str().cmp(other.as_ci_str()))
+    }
+}
+
+impl<const N: usize, F: CaseFold> PartialEq<FixedStr<N>> for FixedStrCi<N, F> {
+    fn eq(&self, other: &FixedStr<N>) -> bool {
+        *self.as_ci_str() == *CiStr::new(other.as_str())
+    }
+}
+
+impl<const N: usize, F: CaseFold> PartialEq<str> for FixedStrCi<N, F> {
+    fn eq(&self, other: &str) -> bool {
+        *self.as_ci_str() == *CiStr::new(other)
+    }
+}
+
+impl<const N: usize, F: CaseFold> PartialEq<&str> for FixedStrCi<N, F> {
+    fn eq(&self, other: &&str) -> bool {
+        *self.as_ci_str() == *CiStr::new(other)
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod ci_tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_fold() {
+        let a = FixedStrCi::<8>::new("Host");
+        let b = FixedStrCi::<16>::new("HOST");
+        assert_eq!(a, b);
+        assert_eq!(a, "host");
+        assert_eq!(a.as_str(), "Host");
+        assert!(FixedStrCi::<8>::new("alpha") < FixedStrCi::<8>::new("BETA"));
+        // Non-ASCII letters are not folded.
+        assert_ne!(FixedStrCi::<8>::new("Ä"), "ä");
+    }
+
+    #[test]
+    fn test_simple_fold() {
+        let a = FixedStrCi::<8, SimpleFold>::new("ΣΑΣ");
+        assert_eq!(a, "σας");
+        assert_eq!(FixedStrCi::<8, SimpleFold>::new("Ä"), "ä");
+        assert_ne!(FixedStrCi::<8, SimpleFold>::new("ß"), "ss");
+        assert_eq!(SimpleFold::fold('K'), 'k');
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_lookup_by_ci_str() {
+        use std::collections::{BTreeSet, HashSet};
+
+        let set: HashSet<FixedStrCi<8>> = ["Accept", "Host"]
+            .iter()
+            .map(|s| FixedStrCi::new(s))
+            .collect();
+        assert!(set.contains(CiStr::new("host")));
+        assert!(!set.contains(CiStr::new("hostname")));
+
+        let tree: BTreeSet<FixedStrCi<8>> = set.into_iter().collect();
+        assert!(tree.contains(CiStr::new("ACCEPT")));
+    }
+
+    #[test]
+    fn test_eq_ignore_ascii_case() {
+        let fs = FixedStr::<4>::new("Ab");
+        let mut buf = FixedStrBuf::<8>::new();
+        buf.try_push_str("aB").unwrap();
+        assert!(fs.eq_ignore_ascii_case(buf));
+        assert!(buf.eq_ignore_ascii_case(fs));
+        // References work too, as held by callers that only borrow the other value.
+        let (fs_ref, buf_ref) = (&fs, &buf);
+        assert!(fs.eq_ignore_ascii_case(buf_ref));
+        assert!(buf.eq_ignore_ascii_case(fs_ref));
+        assert!(!fs.eq_ignore_ascii_case("abc"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_eq_ignore_ascii_case_with_string_ref() {
+        let owned = String::from("dl1ABC");
+        let call = FixedStr::<8>::new("DL1abc");
+        assert!(call.eq_ignore_ascii_case(&owned));
+        assert!(FixedStrBuf::<8>::from(call).eq_ignore_ascii_case(&owned));
+    }
+}
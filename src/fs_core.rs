@@ -7,7 +7,21 @@ use super::*;
 /// Internally, the string is stored in a `[u8; N]` array. Unused bytes are zeroed.
 /// When converting to a `&str`, the first null byte (`\0`) is considered the end of the string.
 ///
-/// **Note:** Zero-length strings (i.e. `N == 0`) are not supported and will cause a panic.
+/// The effective bytes (up to the first null) are always valid UTF‑8: every safe constructor
+/// either validates or truncates its input. Use [`RawFixedStr`] for arbitrary null‑padded bytes.
+///
+/// Equality, ordering, and hashing all operate on the effective bytes only, so bytes after
+/// the first null never affect comparisons. Use [`FixedStr::raw_eq`] and [`FixedStr::raw_cmp`]
+/// to compare the full underlying buffers instead.
+///
+/// **Note:** Zero-length strings (i.e. `N == 0`) are not supported. Constructing one is rejected
+/// at compile time:
+///
+/// ```compile_fail
+/// use fixed_str::FixedStr;
+///
+/// let empty = FixedStr::<0>::default();
+/// ```
 ///
 /// # Examples
 /// ```
@@ -16,7 +30,7 @@ use super::*;
 /// let fs = FixedStr::<5>::new("Hello");
 /// assert_eq!(fs.as_str(), "Hello");
 /// ```
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct FixedStr<const N: usize> {
     pub(super) data: [u8; N],
@@ -29,13 +43,16 @@ impl<const N: usize> FixedStr<N> {
     }
 
     /// Returns `true` if the effective bytes (up to the first null byte) form a valid UTF‑8 string.
+    ///
+    /// This always holds for values built through safe code; it can only fail if an
+    /// `unsafe` constructor was called with invalid input.
     pub fn is_valid(&self) -> bool {
         self.try_as_str().is_ok()
     }
 
     /// Returns the number of valid bytes in the effective string (up to the first null byte).
     pub fn len(&self) -> usize {
-        find_first_null(self)
+        find_first_null(&self.data)
     }
 
     /// Returns whether the effective string is empty.
@@ -68,14 +85,27 @@ impl<const N: usize> FixedStr<N> {
     /// let fs = FixedStr::<5>::new("Hello, World!");
     /// assert_eq!(fs.as_str(), "Hello");
     /// ```
-    ///
-    /// # Panics
-    /// Panics if `N == 0`. Zero‑length strings are not supported.
     pub fn new(input: &str) -> Self {
         let buf = copy_into_buffer(input.as_bytes(), BufferCopyMode::Truncate).unwrap();
         Self { data: buf }
     }
 
+    /// Creates a new `FixedStr` like [`FixedStr::new`] and reports what was truncated.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let (fs, report) = FixedStr::<5>::new_with_report("Hello, World!");
+    /// assert_eq!(fs, "Hello");
+    /// assert_eq!(report.dropped_bytes, 8);
+    /// ```
+    pub fn new_with_report(input: &str) -> (Self, Truncation) {
+        let fixed = Self::new(input);
+        let report = Truncation::measure(input.as_bytes(), fixed.len(), N);
+        (fixed, report)
+    }
+
     /// Creates a new `FixedStr` at compile time with safe truncation.
     ///
     /// The input is copied into the fixed buffer. If the input exceeds the capacity,
@@ -84,11 +114,8 @@ impl<const N: usize> FixedStr<N> {
     ///
     /// **Note:** Truncation is performed without error reporting; if no valid boundary is found,
     /// the result may be empty. Use [`FixedStr::new`] in runtime contexts for stricter handling.
-    ///
-    /// # Panics
-    /// Panics if `N == 0`. Zero‑length strings are not supported.
     pub const fn new_const(input: &str) -> Self {
-        panic_on_zero(N);
+        let () = NonZeroCapacity::<N>::ASSERT;
         let bytes = input.as_bytes();
         let mut buf = [0u8; N];
         let mut i = 0;
@@ -102,6 +129,44 @@ impl<const N: usize> FixedStr<N> {
         Self { data: buf }
     }
 
+    /// Creates a new `FixedStr` from a literal, rejecting anything that would be truncated.
+    ///
+    /// Unlike [`FixedStr::new_const`], this never truncates: it panics if the input is longer
+    /// than `N` bytes or contains a null byte (`\0`). When evaluated in a const context, such as
+    /// a `const` item or the [`fixed_str!`](crate::fixed_str) macro, the panic is reported as a
+    /// compile‑time error.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// const PROTOCOL: FixedStr<8> = FixedStr::from_literal("MQTT");
+    /// assert_eq!(PROTOCOL, "MQTT");
+    /// ```
+    ///
+    /// A literal that does not fit fails to compile:
+    /// ```compile_fail
+    /// use fixed_str::FixedStr;
+    ///
+    /// const PROTOCOL: FixedStr<4> = FixedStr::from_literal("HTTP/2");
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if the input exceeds `N` bytes or contains a null byte.
+    pub const fn from_literal(input: &str) -> Self {
+        let () = NonZeroCapacity::<N>::ASSERT;
+        let bytes = input.as_bytes();
+        assert!(bytes.len() <= N, "literal exceeds FixedStr capacity N");
+        let mut buf = [0u8; N];
+        let mut i = 0;
+        while i < bytes.len() {
+            assert!(bytes[i] != 0, "literal must not contain a null byte");
+            buf[i] = bytes[i];
+            i += 1;
+        }
+        Self { data: buf }
+    }
+
     /// Creates a `FixedStr` from a byte slice.
     ///
     /// If the slice is shorter than `N` bytes, all bytes are copied and the remaining
@@ -109,27 +174,40 @@ impl<const N: usize> FixedStr<N> {
     /// with truncation performed at a valid UTF‑8 boundary.
     ///
     /// **Note:** If the slice contains a null byte (`\0`), the effective string terminates at that position.
-    ///
-    /// # Panics
-    /// Panics if `N == 0`. Zero‑length strings are not supported.
     pub fn from_slice(input: &[u8]) -> Self {
         Self {
             data: copy_into_buffer(input, BufferCopyMode::Truncate).unwrap(),
         }
     }
 
-    /// Creates a `FixedStr` from a byte slice without validating UTF‑8.
+    /// Creates a `FixedStr` like [`FixedStr::from_slice`] and reports what was truncated,
+    /// including whether an invalid UTF‑8 sequence cut the input short.
     ///
-    /// Copies all bytes up to capacity even if the result is not valid UTF‑8.
-    /// Any null byte (`\0`) encountered will cause the effective string to terminate early
-    /// when using `as_str()` or during comparisons.
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
     ///
-    /// # Warning
-    /// Use with care—this may produce values that may cause conversions to panic or comparisons to fail.
+    /// let (fs, report) = FixedStr::<8>::from_slice_with_report(b"ok\xFFrest");
+    /// assert_eq!(fs, "ok");
+    /// assert!(report.incomplete_utf8);
+    /// assert_eq!(report.dropped_bytes, 5);
+    /// ```
+    pub fn from_slice_with_report(input: &[u8]) -> (Self, Truncation) {
+        let fixed = Self::from_slice(input);
+        let report = Truncation::measure(input, fixed.len(), N);
+        (fixed, report)
+    }
+
+    /// Creates a `FixedStr` from a byte slice without validating UTF‑8.
     ///
-    /// # Panics
-    /// Panics if `N == 0`. Zero‑length strings are not supported.
-    pub fn from_slice_unsafe(slice: &[u8]) -> Self {
+    /// Copies all bytes up to capacity. Any null byte (`\0`) encountered will cause the
+    /// effective string to terminate early when using `as_str()` or during comparisons.
+    /// Use [`RawFixedStr::from_slice`] for data that has not been validated.
+    ///
+    /// # Safety
+    /// The bytes that end up before the first null (after the copy is cut at `N` bytes)
+    /// must be valid UTF‑8.
+    pub unsafe fn from_slice_unsafe(slice: &[u8]) -> Self {
         Self {
             data: copy_into_buffer(slice, BufferCopyMode::Slice).unwrap(),
         }
@@ -140,9 +218,6 @@ impl<const N: usize> FixedStr<N> {
     /// Interprets the entire array as a UTF‑8 string, truncating only at invalid boundaries.
     ///
     /// **Note:** If the array contains a null byte (`\0`), the string will terminate at that point.
-    ///
-    /// # Panics
-    /// Panics if `N == 0`. Zero‑length strings are not supported.
     pub fn from_bytes(bytes: [u8; N]) -> Self {
         Self {
             data: copy_into_buffer(&bytes, BufferCopyMode::Truncate).unwrap(),
@@ -151,20 +226,86 @@ impl<const N: usize> FixedStr<N> {
 
     /// Stores a byte array without validating UTF‑8.
     ///
-    /// The bytes are used as‑is, which may result in an invalid UTF‑8 string.
-    /// The first null byte (`\0`) still acts as a terminator in conversions and comparisons.
-    ///
-    /// # Warning
-    /// Use with care—this may produce values that may cause conversions to panic or comparisons to fail.
+    /// The bytes are used as‑is. The first null byte (`\0`) still acts as a terminator in
+    /// conversions and comparisons. Use [`RawFixedStr::from_bytes`] for data that has not
+    /// been validated.
     ///
-    /// # Panics
-    /// Panics if `N == 0`. Zero‑length strings are not supported.
-    pub fn from_bytes_unsafe(bytes: [u8; N]) -> Self {
+    /// # Safety
+    /// The bytes before the first null must be valid UTF‑8.
+    pub unsafe fn from_bytes_unsafe(bytes: [u8; N]) -> Self {
         Self {
             data: copy_into_buffer(&bytes, BufferCopyMode::Slice).unwrap(),
         }
     }
 
+    /// Formats a value directly into a new `FixedStr` without allocating.
+    ///
+    /// Output that does not fit is truncated at the last whole UTF‑8 character.
+    /// See [`fixed_format_lossy!`](crate::fixed_format_lossy) for a `format!`-style shorthand.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use fixed_str::FixedStr;
+    ///
+    /// let ip = Ipv4Addr::new(192, 168, 100, 200);
+    /// assert_eq!(FixedStr::<10>::from_display(&ip), "192.168.10");
+    /// ```
+    pub fn from_display<T: fmt::Display + ?Sized>(value: &T) -> Self {
+        let mut buf = FixedStrBuf::<N>::new();
+        // `LossyWriter` never fails; an error could only come from `value` itself.
+        let _ = write!(buf.lossy_writer(), "{}", value);
+        buf.finalize()
+    }
+
+    /// Formats a value directly into a new `FixedStr`, failing instead of truncating.
+    ///
+    /// See [`fixed_format!`](crate::fixed_format) for a `format!`-style shorthand.
+    ///
+    /// # Errors
+    /// - Returns `FixedStrError::Overflow` if the formatted output is longer than N bytes,
+    ///   with `found` set to the full output length.
+    /// - Returns `FixedStrError::EmbeddedNull` if the formatted output contains a null byte.
+    /// - Returns `FixedStrError::Format` if the `Display` implementation of `value` fails.
+    pub fn try_from_display<T: fmt::Display + ?Sized>(value: &T) -> Result<Self, FixedStrError> {
+        let mut buf = FixedStrBuf::<N>::new();
+        let mut writer = buf.lossy_writer();
+        // `LossyWriter` never fails, so an error can only come from `value` itself.
+        if write!(writer, "{}", value).is_err() {
+            return Err(FixedStrError::Format);
+        }
+        if writer.requested() > N {
+            return Err(FixedStrError::Overflow {
+                available: N,
+                found: writer.requested(),
+            });
+        }
+        if let Some(position) = writer.nul_position() {
+            return Err(FixedStrError::EmbeddedNull { position });
+        }
+        Ok(buf.finalize())
+    }
+
+    /// Collects the items of an iterator into a new `FixedStr`, failing instead of truncating.
+    ///
+    /// See [`FixedStrBuf::try_from_iter`] for details.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let words = ["alpha", "beta", "gamma"];
+    /// let initials = FixedStr::<3>::try_from_iter(words.iter().filter_map(|w| w.chars().next()));
+    /// assert_eq!(initials.unwrap(), "abg");
+    /// ```
+    pub fn try_from_iter<I>(iter: I) -> Result<Self, FixedStrError>
+    where
+        I: IntoIterator,
+        I::Item: fmt::Display,
+    {
+        FixedStrBuf::try_from_iter(iter).map(FixedStrBuf::finalize)
+    }
+
     //****************************************************************************
     //  Modifiers
     //****************************************************************************
@@ -175,9 +316,6 @@ impl<const N: usize> FixedStr<N> {
     /// bytes, an error is returned. If it is shorter, the remaining bytes are zero‑padded.
     ///
     /// **Warning:** If the input contains a null byte (`\0`), the string terminates at that point.
-    ///
-    /// # Panics
-    /// Panics if `N == 0`. Zero‑length strings are not supported.
     pub fn set(&mut self, input: &str) -> Result<(), FixedStrError> {
         self.data = copy_into_buffer(input.effective_bytes(), BufferCopyMode::Exact)?;
         Ok(())
@@ -197,27 +335,83 @@ impl<const N: usize> FixedStr<N> {
     /// // "World!" is truncated to "World" because the capacity is 5 bytes.
     /// assert_eq!(fs.as_str(), "World");
     /// ```
-    ///
-    /// # Panics
-    /// Panics if `N == 0`. Zero‑length strings are not supported.
     pub fn set_lossy(&mut self, input: &str) {
         self.data = copy_into_buffer(input.effective_bytes(), BufferCopyMode::Truncate).unwrap();
     }
 
+    /// Updates the `FixedStr` like [`FixedStr::set_lossy`] and reports what was truncated.
+    pub fn set_lossy_with_report(&mut self, input: &str) -> Truncation {
+        self.set_lossy(input);
+        Truncation::measure(input.as_bytes(), self.len(), N)
+    }
+
     /// Clears the `FixedStr`, setting all bytes to zero.
     pub fn clear(&mut self) {
         self.data = [0u8; N];
     }
 
-    /// Truncates the fixed string to `new_len` bytes.
+    /// Truncates the fixed string to at most `new_len` bytes.
     ///
     /// If `new_len` is less than the current effective length, the effective string is cut
-    /// off at `new_len` and all bytes from `new_len` to capacity are set to zero.
+    /// off at the last character boundary at or before `new_len`, so a multi‑byte character
+    /// is never split. All bytes from the cut to capacity are set to zero.
     /// If `new_len` is greater than or equal to the current effective length, this method does nothing.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let mut fs = FixedStr::<8>::new("héllo");
+    /// fs.truncate(2); // would split 'é', so the cut moves back to 1
+    /// assert_eq!(fs, "h");
+    /// ```
     pub fn truncate(&mut self, new_len: usize) {
         let current = self.len();
         if new_len < current {
-            self.data[new_len..N].fill(0);
+            let cut = floor_char_boundary(&self.data[..current], new_len);
+            self.data[cut..N].fill(0);
+        }
+    }
+
+    /// Truncates the fixed string to exactly `new_len` bytes.
+    ///
+    /// Unlike [`truncate`](Self::truncate), this does not round down when `new_len` falls
+    /// inside a multi‑byte character; the string is left unchanged and an error is returned.
+    /// If `new_len` is greater than or equal to the current effective length, this method does nothing.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::Truncated` with the number of bytes that `truncate(new_len)`
+    /// would have removed if `new_len` is not on a character boundary.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::{FixedStr, FixedStrError};
+    ///
+    /// let mut fs = FixedStr::<8>::new("héllo");
+    /// assert_eq!(fs.try_truncate(2), Err(FixedStrError::Truncated { dropped: 5 }));
+    /// assert_eq!(fs.try_truncate(3), Ok(()));
+    /// assert_eq!(fs, "hé");
+    /// ```
+    pub fn try_truncate(&mut self, new_len: usize) -> Result<(), FixedStrError> {
+        let current = self.len();
+        if new_len < current {
+            let cut = floor_char_boundary(&self.data[..current], new_len);
+            if cut != new_len {
+                return Err(FixedStrError::Truncated {
+                    dropped: current - cut,
+                });
+            }
+            self.data[cut..N].fill(0);
+        }
+        Ok(())
+    }
+
+    /// Truncates the fixed string to at most `n` characters.
+    ///
+    /// If the effective string has `n` characters or fewer, this method does nothing.
+    pub fn truncate_chars(&mut self, n: usize) {
+        if let Some((idx, _)) = self.as_str().char_indices().nth(n) {
+            self.truncate(idx);
         }
     }
 
@@ -226,16 +420,17 @@ impl<const N: usize> FixedStr<N> {
     //****************************************************************************
 
     /// Returns the string slice representation of the effective string.
-    #[track_caller]
     pub fn as_str(&self) -> &str {
-        truncate_utf8_lossy(self, N)
+        // SAFETY: The effective bytes of a `FixedStr` are always valid UTF‑8.
+        unsafe { str::from_utf8_unchecked(self.effective_bytes()) }
     }
 
     /// Attempts to interpret the stored effective bytes as a UTF‑8 string.
     ///
-    /// Returns an error if the data up to the first null byte is not valid UTF‑8.
+    /// Returns an error if the data up to the first null byte is not valid UTF‑8,
+    /// which can only happen if an `unsafe` constructor was misused.
     pub fn try_as_str(&self) -> Result<&str, FixedStrError> {
-        str::from_utf8(self.effective_bytes()).map_err(|_| FixedStrError::InvalidUtf8)
+        Ok(str::from_utf8(self.effective_bytes())?)
     }
 
     /// Returns the raw byte array stored in the `FixedStr`.
@@ -245,22 +440,291 @@ impl<const N: usize> FixedStr<N> {
 
     #[cfg(feature = "const_mut_refs")]
     /// Returns the raw byte array stored in the `FixedStr` as mutable.
-    pub const fn as_mut_bytes(&mut self) -> &mut [u8] {
+    ///
+    /// Prefer [`FixedStr::as_mut_str`] or [`FixedStr::edit_bytes`], which cannot break the string.
+    ///
+    /// # Safety
+    /// The caller must ensure that the effective bytes (up to the first null) are valid UTF‑8
+    /// when the borrow ends.
+    pub const unsafe fn as_mut_bytes(&mut self) -> &mut [u8] {
         &mut self.data
     }
 
     #[cfg(not(feature = "const_mut_refs"))]
     /// Returns the raw byte array stored in the `FixedStr` as mutable.
-    pub fn as_mut_bytes(&mut self) -> &mut [u8] {
+    ///
+    /// Prefer [`FixedStr::as_mut_str`] or [`FixedStr::edit_bytes`], which cannot break the string.
+    ///
+    /// # Safety
+    /// The caller must ensure that the effective bytes (up to the first null) are valid UTF‑8
+    /// when the borrow ends.
+    pub unsafe fn as_mut_bytes(&mut self) -> &mut [u8] {
         &mut self.data
     }
 
+    /// Returns a mutable string slice over the effective string.
+    pub fn as_mut_str(&mut self) -> &mut str {
+        let len = self.len();
+        // SAFETY: The effective bytes are valid UTF‑8, and safe `&mut str` methods cannot
+        // make them invalid.
+        unsafe { str::from_utf8_unchecked_mut(&mut self.data[..len]) }
+    }
+
+    /// Converts the effective string to ASCII upper case in place.
+    pub fn make_ascii_uppercase(&mut self) {
+        self.as_mut_str().make_ascii_uppercase();
+    }
+
+    /// Converts the effective string to ASCII lower case in place.
+    pub fn make_ascii_lowercase(&mut self) {
+        self.as_mut_str().make_ascii_lowercase();
+    }
+
+    /// Edits the raw buffer through a closure and revalidates the result.
+    ///
+    /// The closure receives the full `[u8; N]` buffer. Writing a null byte ends the effective
+    /// string at that point, and everything after the first null is zeroed. If the effective
+    /// bytes are not valid UTF‑8 afterwards, the buffer is restored to its previous contents
+    /// and an error is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let mut fs = FixedStr::<8>::new("rust");
+    /// fs.edit_bytes(|b| b[0] = b'R').unwrap();
+    /// assert_eq!(fs, "Rust");
+    ///
+    /// assert!(fs.edit_bytes(|b| b[1] = 0xFF).is_err());
+    /// assert_eq!(fs, "Rust");
+    /// ```
+    pub fn edit_bytes<R>(&mut self, f: impl FnOnce(&mut [u8; N]) -> R) -> Result<R, FixedStrError> {
+        let snapshot = self.data;
+        let result = f(&mut self.data);
+        match self.try_as_str() {
+            Ok(_) => {
+                // Bytes after the null are never validated, so drop them instead of keeping garbage.
+                let len = self.len();
+                self.data[len..].fill(0);
+                Ok(result)
+            }
+            Err(e) => {
+                self.data = snapshot;
+                Err(e)
+            }
+        }
+    }
+
+    /// Compares the full underlying buffers, including any bytes after the first null.
+    ///
+    /// Unlike `==`, which only looks at the effective string, this distinguishes values
+    /// whose trailing bytes differ.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// // SAFETY: The bytes before the first null are ASCII.
+    /// let a = unsafe { FixedStr::<5>::from_bytes_unsafe(*b"ab\0xx") };
+    /// let b = unsafe { FixedStr::<5>::from_bytes_unsafe(*b"ab\0yy") };
+    /// assert_eq!(a, b);
+    /// assert!(!a.raw_eq(&b));
+    /// ```
+    pub fn raw_eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+
+    /// Orders two values by their full underlying buffers, including any bytes after the first null.
+    pub fn raw_cmp(&self, other: &Self) -> Ordering {
+        self.data.cmp(&other.data)
+    }
+
+    /// Compares the effective string with that of a `FixedStr` or `FixedStrBuf` of any capacity.
+    ///
+    /// `==` only accepts the same capacity, so that `FixedStr::new("..")` on the right‑hand side
+    /// can still infer it.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let long = FixedStr::<32>::new("sensor");
+    /// assert!(long.eq_fixed(&FixedStr::<16>::new("sensor")));
+    /// ```
+    pub fn eq_fixed<T: EffectiveBytes>(&self, other: &T) -> bool {
+        self.effective_bytes() == other.effective_bytes()
+    }
+
+    /// Orders the effective string against that of a `FixedStr` or `FixedStrBuf` of any capacity.
+    pub fn cmp_fixed<T: EffectiveBytes>(&self, other: &T) -> Ordering {
+        self.effective_bytes().cmp(other.effective_bytes())
+    }
+
     /// Returns an iterator over the entire internal byte array,
     /// including trailing zeroes beyond the effective string.
     pub fn byte_iter(&self) -> impl Iterator<Item = u8> + '_ {
         self.data.iter().copied()
     }
 
+    //****************************************************************************
+    //  Capacity Conversions
+    //****************************************************************************
+
+    /// Copies the content into a `FixedStr` of capacity `M`, truncating at the last
+    /// UTF‑8 boundary if it does not fit.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let fs = FixedStr::<8>::new("héllo");
+    /// assert_eq!(fs.resize::<2>(), "h");
+    /// assert_eq!(fs.resize::<16>(), "héllo");
+    /// ```
+    pub fn resize<const M: usize>(&self) -> FixedStr<M> {
+        FixedStr::new(self.as_str())
+    }
+
+    /// Copies the content into a `FixedStr` of capacity `M`.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::Overflow` if the effective string is longer than `M` bytes.
+    pub fn try_resize<const M: usize>(&self) -> Result<FixedStr<M>, FixedStrError> {
+        Ok(FixedStr {
+            data: copy_into_buffer(self.effective_bytes(), BufferCopyMode::Exact)?,
+        })
+    }
+
+    /// Copies the content into a `FixedStr` of capacity `M >= N`, which always fits.
+    ///
+    /// Using a smaller `M` is rejected at compile time:
+    ///
+    /// ```compile_fail
+    /// use fixed_str::FixedStr;
+    ///
+    /// let narrow: FixedStr<4> = FixedStr::<8>::new("id").widen();
+    /// ```
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let v1 = FixedStr::<8>::new("username");
+    /// let v2: FixedStr<16> = v1.widen();
+    /// assert_eq!(v2, "username");
+    /// ```
+    pub fn widen<const M: usize>(&self) -> FixedStr<M> {
+        let () = AtLeastCapacity::<N, M>::ASSERT;
+        let bytes = self.effective_bytes();
+        let mut data = [0u8; M];
+        data[..bytes.len()].copy_from_slice(bytes);
+        FixedStr { data }
+    }
+
+    //****************************************************************************
+    //  Case Conversion
+    //****************************************************************************
+
+    /// Returns the Unicode upper‑case equivalent of the string, without allocating.
+    ///
+    /// Case mapping can turn one character into several (`'ß'` becomes `"SS"`) and change the
+    /// byte length (`'ŉ'` becomes `"ʼN"`), so the result is finished under `policy` if it does not
+    /// fit into `N` bytes: pass [`Reject`] for a strict conversion or [`Truncate`] for a lossy one.
+    ///
+    /// # Errors
+    /// Returns the error produced by `policy`, for example `FixedStrError::Overflow` for [`Reject`].
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::{FixedStr, FixedStrError, Reject, Truncate};
+    ///
+    /// let fs = FixedStr::<8>::new("straße");
+    /// assert_eq!(fs.to_uppercase(Reject).unwrap(), "STRASSE");
+    /// assert_eq!(fs.to_uppercase_into::<4, _>(Truncate).unwrap(), "STRA");
+    /// assert_eq!(
+    ///     fs.to_uppercase_into::<4, _>(Reject),
+    ///     Err(FixedStrError::Overflow { available: 4, found: 7 })
+    /// );
+    /// ```
+    pub fn to_uppercase<Pol: OverflowPolicy>(&self, policy: Pol) -> Result<Self, FixedStrError> {
+        self.to_uppercase_into(policy)
+    }
+
+    /// Returns the Unicode lower‑case equivalent of the string, without allocating.
+    ///
+    /// Each character is mapped on its own with `char::to_lowercase`, so unlike
+    /// `str::to_lowercase`, a word‑final `'Σ'` becomes `'σ'` rather than `'ς'`.
+    /// See [`to_uppercase`](Self::to_uppercase) for how overflow is handled.
+    ///
+    /// # Errors
+    /// Returns the error produced by `policy`.
+    pub fn to_lowercase<Pol: OverflowPolicy>(&self, policy: Pol) -> Result<Self, FixedStrError> {
+        self.to_lowercase_into(policy)
+    }
+
+    /// Returns the upper‑case equivalent of the string in a `FixedStr` of capacity `M`.
+    ///
+    /// See [`to_uppercase`](Self::to_uppercase).
+    ///
+    /// # Errors
+    /// Returns the error produced by `policy`.
+    pub fn to_uppercase_into<const M: usize, Pol: OverflowPolicy>(
+        &self,
+        _policy: Pol,
+    ) -> Result<FixedStr<M>, FixedStrError> {
+        self.map_chars_into::<M, Pol, _>(char::to_uppercase)
+    }
+
+    /// Returns the lower‑case equivalent of the string in a `FixedStr` of capacity `M`.
+    ///
+    /// See [`to_lowercase`](Self::to_lowercase).
+    ///
+    /// # Errors
+    /// Returns the error produced by `policy`.
+    pub fn to_lowercase_into<const M: usize, Pol: OverflowPolicy>(
+        &self,
+        _policy: Pol,
+    ) -> Result<FixedStr<M>, FixedStrError> {
+        self.map_chars_into::<M, Pol, _>(char::to_lowercase)
+    }
+
+    /// Returns a copy with ASCII letters converted to upper case; other characters are unchanged.
+    ///
+    /// ASCII case mapping never changes the length, so this cannot fail.
+    pub fn to_ascii_uppercase(&self) -> Self {
+        let mut copy = *self;
+        copy.make_ascii_uppercase();
+        copy
+    }
+
+    /// Returns a copy with ASCII letters converted to lower case; other characters are unchanged.
+    ///
+    /// ASCII case mapping never changes the length, so this cannot fail.
+    pub fn to_ascii_lowercase(&self) -> Self {
+        let mut copy = *self;
+        copy.make_ascii_lowercase();
+        copy
+    }
+
+    /// Writes the characters produced by `map` into a new `FixedStr<M>` under policy `Pol`.
+    fn map_chars_into<const M: usize, Pol, I>(
+        &self,
+        map: fn(char) -> I,
+    ) -> Result<FixedStr<M>, FixedStrError>
+    where
+        Pol: OverflowPolicy,
+        I: Iterator<Item = char>,
+    {
+        let mut output = FixedStrBuf::<M>::new();
+        let mut writer = output.lossy_writer();
+        // Keep writing after a cut so that the report counts the full output length.
+        for c in self.as_str().chars().flat_map(map) {
+            let _ = writer.write_char(c);
+        }
+        let report = writer.report();
+        Pol::resolve(output, report)
+    }
+
     //****************************************************************************
     //  std Functions
     //****************************************************************************
@@ -0,0 +1,521 @@
+// fixed_str/src/fs_edit.rs
+
+use super::*;
+use core::ops::{Bound, RangeBounds};
+
+/// In‑place editing for `FixedStrBuf`.
+///
+/// Like `String`, every index is a byte offset that must lie on a character boundary; the
+/// methods panic otherwise. The plainly named methods (`insert`, `insert_str`, `replace_range`)
+/// also panic if the result does not fit. Their `try_*` variants leave the buffer unchanged and
+/// return `FixedStrError::Overflow` instead, and the `*_lossy` variants keep as many whole
+/// characters of the inserted text as fit. Inserted text ends at its first null byte.
+impl<const N: usize> FixedStrBuf<N> {
+    /// Inserts a character at byte index `idx`.
+    ///
+    /// # Panics
+    /// Panics if `idx` is larger than the length or does not lie on a character boundary, or if
+    /// the character does not fit. Use [`try_insert`](Self::try_insert) to handle overflow.
+    pub fn insert(&mut self, idx: usize, ch: char) {
+        self.insert_str(idx, ch.encode_utf8(&mut [0u8; 4]));
+    }
+
+    /// Inserts a string at byte index `idx`.
+    ///
+    /// # Panics
+    /// Panics if `idx` is larger than the length or does not lie on a character boundary, or if
+    /// the string does not fit. Use [`try_insert_str`](Self::try_insert_str) to handle overflow.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStrBuf;
+    ///
+    /// let mut buf = FixedStrBuf::<16>::try_from("world").unwrap();
+    /// buf.insert_str(0, "o, ");
+    /// buf.insert(0, 'n');
+    /// assert_eq!(buf.as_str(), "no, world");
+    /// ```
+    pub fn insert_str(&mut self, idx: usize, s: &str) {
+        self.replace_range(idx..idx, s);
+    }
+
+    /// Inserts a character at byte index `idx`.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::Overflow` if the character does not fit.
+    ///
+    /// # Panics
+    /// Panics if `idx` is larger than the length or does not lie on a character boundary.
+    pub fn try_insert(&mut self, idx: usize, ch: char) -> Result<(), FixedStrError> {
+        self.try_insert_str(idx, ch.encode_utf8(&mut [0u8; 4]))
+    }
+
+    /// Inserts a string at byte index `idx`.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::Overflow` if the string does not fit; nothing is inserted.
+    ///
+    /// # Panics
+    /// Panics if `idx` is larger than the length or does not lie on a character boundary.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStrBuf;
+    ///
+    /// let mut buf = FixedStrBuf::<8>::try_from("ac").unwrap();
+    /// buf.try_insert_str(1, "b").unwrap();
+    /// assert_eq!(buf.as_str(), "abc");
+    /// assert!(buf.try_insert_str(0, "123456").is_err());
+    /// ```
+    pub fn try_insert_str(&mut self, idx: usize, s: &str) -> Result<(), FixedStrError> {
+        self.try_replace_range(idx..idx, s)
+    }
+
+    /// Inserts as many whole characters of `s` at byte index `idx` as fit.
+    ///
+    /// Returns `true` if all of `s` was inserted.
+    ///
+    /// # Panics
+    /// Panics if `idx` is larger than the length or does not lie on a character boundary.
+    pub fn insert_str_lossy(&mut self, idx: usize, s: &str) -> bool {
+        self.replace_range_lossy(idx..idx, s)
+    }
+
+    /// Removes the character at byte index `idx` and returns it.
+    ///
+    /// # Panics
+    /// Panics if `idx` is not smaller than the length or does not lie on a character boundary.
+    pub fn remove(&mut self, idx: usize) -> char {
+        let ch = match self.as_str()[idx..].chars().next() {
+            Some(ch) => ch,
+            None => panic!("cannot remove a char from the end of a string"),
+        };
+        self.splice(idx, idx + ch.len_utf8(), &[]);
+        ch
+    }
+
+    /// Removes the last character and returns it, or `None` if the buffer is empty.
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next_back()?;
+        self.truncate(self.len - ch.len_utf8());
+        Some(ch)
+    }
+
+    /// Keeps only the characters for which `f` returns `true`, zeroing the freed bytes.
+    ///
+    /// If `f` panics, the characters it has not yet accepted or rejected are kept, as with
+    /// `String::retain`, so the buffer stays valid UTF‑8.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStrBuf;
+    ///
+    /// let mut buf = FixedStrBuf::<16>::try_from("a1-b2-c3").unwrap();
+    /// buf.retain(|c| c.is_ascii_alphabetic());
+    /// assert_eq!(buf.as_str(), "abc");
+    /// ```
+    pub fn retain<F: FnMut(char) -> bool>(&mut self, mut f: F) {
+        let mut guard = RetainGuard {
+            buf: self,
+            read: 0,
+            write: 0,
+        };
+        while guard.read < guard.buf.len {
+            // SAFETY: `read` is a character boundary of the original content, and the bytes from
+            // `read` to `len` have not been written to yet, so they are still valid UTF‑8.
+            let rest =
+                unsafe { str::from_utf8_unchecked(&guard.buf.buffer[guard.read..guard.buf.len]) };
+            let ch = rest.chars().next().unwrap_or_default();
+            let width = ch.len_utf8();
+            if f(ch) {
+                let (read, write) = (guard.read, guard.write);
+                guard.buf.buffer.copy_within(read..read + width, write);
+                guard.write += width;
+            }
+            guard.read += width;
+        }
+    }
+
+    /// Replaces the given byte range with `s`.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds or does not lie on character boundaries, or if the
+    /// result does not fit. Use [`try_replace_range`](Self::try_replace_range) to handle overflow.
+    pub fn replace_range<R: RangeBounds<usize>>(&mut self, range: R, s: &str) {
+        if let Err(e) = self.try_replace_range(range, s) {
+            panic!("replacement does not fit into FixedStrBuf<{}>: {}", N, e);
+        }
+    }
+
+    /// Replaces the given byte range with `s`.
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::Overflow` if the result does not fit; the buffer is unchanged.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds or does not lie on character boundaries.
+    pub fn try_replace_range<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+        s: &str,
+    ) -> Result<(), FixedStrError> {
+        let (start, end) = self.char_range(range);
+        let bytes = s.effective_bytes();
+        let available = self.remaining() + (end - start);
+        if bytes.len() > available {
+            return Err(FixedStrError::Overflow {
+                available,
+                found: bytes.len(),
+            });
+        }
+        self.splice(start, end, bytes);
+        Ok(())
+    }
+
+    /// Replaces the given byte range with as many whole characters of `s` as fit.
+    ///
+    /// Returns `true` if all of `s` was inserted.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds or does not lie on character boundaries.
+    pub fn replace_range_lossy<R: RangeBounds<usize>>(&mut self, range: R, s: &str) -> bool {
+        let (start, end) = self.char_range(range);
+        let bytes = s.effective_bytes();
+        let kept = truncate_utf8_lossy(bytes, self.remaining() + (end - start)).as_bytes();
+        self.splice(start, end, kept);
+        kept.len() == bytes.len()
+    }
+
+    /// Removes the given byte range and returns the removed characters as an iterator.
+    ///
+    /// Unlike `String::drain`, the range is removed immediately and the returned [`Drain`]
+    /// owns a copy of it, so the buffer can be used again right away.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds or does not lie on character boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStrBuf;
+    ///
+    /// let mut buf = FixedStrBuf::<16>::try_from("key=value").unwrap();
+    /// let key: String = buf.drain(..4).collect();
+    /// assert_eq!(key, "key=");
+    /// assert_eq!(buf.as_str(), "value");
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<N> {
+        let (start, end) = self.char_range(range);
+        let mut removed = FixedStrBuf::new();
+        removed.buffer[..end - start].copy_from_slice(&self.buffer[start..end]);
+        removed.len = end - start;
+        self.splice(start, end, &[]);
+        Drain {
+            chars: removed.chars(),
+        }
+    }
+
+    /// Resolves `range` against the current content and checks that it lies on character boundaries.
+    #[track_caller]
+    fn char_range<R: RangeBounds<usize>>(&self, range: R) -> (usize, usize) {
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n
+                .checked_add(1)
+                .expect("range start is out of bounds: excluded usize::MAX"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n
+                .checked_add(1)
+                .expect("range end is out of bounds: included usize::MAX"),
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => self.len,
+        };
+        assert!(start <= end, "range start {} is after end {}", start, end);
+        assert!(end <= self.len, "range end {} is out of bounds", end);
+        let s = self.as_str();
+        assert!(
+            s.is_char_boundary(start) && s.is_char_boundary(end),
+            "range {}..{} does not lie on character boundaries",
+            start,
+            end
+        );
+        (start, end)
+    }
+
+    /// Replaces `start..end` with `bytes`, which the caller has checked to fit.
+    fn splice(&mut self, start: usize, end: usize, bytes: &[u8]) {
+        let new_len = self.len - (end - start) + bytes.len();
+        self.buffer.copy_within(end..self.len, start + bytes.len());
+        self.buffer[start..start + bytes.len()].copy_from_slice(bytes);
+        if new_len < self.len {
+            self.buffer[new_len..self.len].fill(0);
+        }
+        self.len = new_len;
+    }
+}
+
+/// Closes the gap left by [`FixedStrBuf::retain`], even if its closure panics.
+struct RetainGuard<'a, const N: usize> {
+    buf: &'a mut FixedStrBuf<N>,
+    read: usize,
+    write: usize,
+}
+
+impl<const N: usize> Drop for RetainGuard<'_, N> {
+    fn drop(&mut self) {
+        let len = self.buf.len;
+        // Keep the characters that were not examined, then zero what is left behind.
+        self.buf.buffer.copy_within(self.read..len, self.write);
+        let new_len = self.write + (len - self.read);
+        self.buf.buffer[new_len..len].fill(0);
+        self.buf.len = new_len;
+    }
+}
+
+//******************************************************************************
+//  Transactions
+//******************************************************************************
+
+/// A saved length of a [`FixedStrBuf`], created by [`FixedStrBuf::checkpoint`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Mark {
+    len: usize,
+}
+
+impl Mark {
+    /// Returns the length of the buffer when the mark was taken.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the mark was taken on an empty buffer.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// All‑or‑nothing appends for `FixedStrBuf`.
+///
+/// A [`Mark`] records only the current length, so rolling back undoes appends made after it.
+/// Edits to the content before the mark, such as [`FixedStrBuf::remove`], are not undone.
+impl<const N: usize> FixedStrBuf<N> {
+    /// Records the current length so that later appends can be undone with [`rollback`](Self::rollback).
+    pub fn checkpoint(&self) -> Mark {
+        Mark { len: self.len }
+    }
+
+    /// Discards everything written after `mark` and zeroes the freed bytes.
+    ///
+    /// If the buffer is already shorter than the mark, nothing changes.
+    pub fn rollback(&mut self, mark: Mark) {
+        self.truncate(mark.len);
+    }
+
+    /// Runs `f` on the buffer and rolls back everything it appended if it returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::fmt::Write;
+    /// use fixed_str::FixedStrBuf;
+    ///
+    /// let mut buf = FixedStrBuf::<12>::new();
+    /// buf.try_push_str("id=").unwrap();
+    /// // The second field does not fit, so the first one is removed as well.
+    /// let result = buf.transaction(|b| write!(b, "{};{}", 42, "too long"));
+    /// assert!(result.is_err());
+    /// assert_eq!(buf.as_str(), "id=");
+    /// ```
+    pub fn transaction<T, E, F>(&mut self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut Self) -> Result<T, E>,
+    {
+        let mark = self.checkpoint();
+        let result = f(self);
+        if result.is_err() {
+            self.rollback(mark);
+        }
+        result
+    }
+}
+
+/// An iterator over the characters removed by [`FixedStrBuf::drain`].
+///
+/// The removed text is owned by the iterator, which walks it like [`Chars`];
+/// [`Drain::as_str`] returns what has not been yielded yet.
+#[derive(Clone, Debug)]
+pub struct Drain<const N: usize> {
+    chars: Chars<N>,
+}
+
+impl<const N: usize> Drain<N> {
+    /// Returns the characters that have not been yielded yet.
+    pub fn as_str(&self) -> &str {
+        self.chars.as_str()
+    }
+}
+
+impl<const N: usize> Iterator for Drain<N> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        self.chars.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chars.size_hint()
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for Drain<N> {
+    fn next_back(&mut self) -> Option<char> {
+        self.chars.next_back()
+    }
+}
+
+impl<const N: usize> core::iter::FusedIterator for Drain<N> {}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod edit_tests {
+    use super::*;
+
+    fn buf<const N: usize>(s: &str) -> FixedStrBuf<N> {
+        FixedStrBuf::try_from(s).unwrap()
+    }
+
+    #[test]
+    fn test_insert_respects_capacity() {
+        let mut b = buf::<6>("héo");
+        b.try_insert(3, 'l').unwrap();
+        assert_eq!(b.as_str(), "hélo");
+        assert_eq!(
+            b.try_insert(0, '€'),
+            Err(FixedStrError::Overflow {
+                available: 1,
+                found: 3
+            })
+        );
+        assert!(!b.insert_str_lossy(4, "l€"));
+        assert_eq!(b.as_str(), "héllo");
+    }
+
+    #[test]
+    fn test_plain_methods_edit_in_place() {
+        let mut b = buf::<8>("ac");
+        b.insert(1, 'b');
+        b.insert_str(3, "d");
+        b.replace_range(..1, "AA");
+        assert_eq!(b.as_str(), "AAbcd");
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit")]
+    fn test_insert_str_overflow_panics() {
+        let mut b = buf::<4>("abc");
+        b.insert_str(0, "xy");
+    }
+
+    #[test]
+    #[should_panic(expected = "character boundaries")]
+    fn test_insert_inside_char_panics() {
+        let mut b = buf::<8>("é");
+        let _ = b.try_insert(1, 'x');
+    }
+
+    #[test]
+    fn test_remove_and_pop() {
+        let mut b = buf::<8>("aéb");
+        assert_eq!(b.remove(1), 'é');
+        assert_eq!(b.as_str(), "ab");
+        assert_eq!(b.pop(), Some('b'));
+        assert_eq!(b.pop(), Some('a'));
+        assert_eq!(b.pop(), None);
+        assert_eq!(b.raw_cmp(&FixedStrBuf::new()), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_retain_zeroes_tail() {
+        let mut b = buf::<12>("a€b€c");
+        b.retain(|c| c != '€');
+        assert_eq!(b.as_str(), "abc");
+        assert!(b.raw_eq(&buf::<12>("abc")));
+    }
+
+    #[test]
+    fn test_replace_range() {
+        let mut b = buf::<8>("abcdef");
+        b.try_replace_range(1..3, "XYZW").unwrap();
+        assert_eq!(b.as_str(), "aXYZWdef");
+        assert!(b.try_replace_range(..1, "12").is_err());
+        assert!(!b.replace_range_lossy(5.., "ééé"));
+        assert_eq!(b.as_str(), "aXYZWé");
+        b.try_replace_range(1..=4, "").unwrap();
+        assert_eq!(b.as_str(), "aé");
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_replace_range_to_usize_max_panics() {
+        let mut b = buf::<8>("abc");
+        let _ = b.try_replace_range(1..=usize::MAX, "");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_retain_panic_keeps_valid_utf8() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let mut b = buf::<8>("éa€");
+        let mut calls = 0;
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            b.retain(|c| {
+                calls += 1;
+                if calls == 3 {
+                    panic!("closure failed");
+                }
+                c != 'é'
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(b.try_as_str(), Ok("a€"));
+        assert_eq!(b.len(), 4);
+        assert!(b.raw_eq(&buf::<8>("a€")));
+        b.retain(|c| c == '€');
+        assert_eq!(b.as_str(), "€");
+    }
+
+    #[test]
+    fn test_checkpoint_and_rollback() {
+        let mut b = buf::<8>("ab");
+        let mark = b.checkpoint();
+        assert_eq!(mark.len(), 2);
+        b.try_push_str("cdé").unwrap();
+        b.rollback(mark);
+        assert_eq!(b.as_str(), "ab");
+        assert!(b.raw_eq(&buf::<8>("ab")));
+
+        let result: Result<(), FixedStrError> = b.transaction(|b| {
+            b.try_push_str("cd")?;
+            b.try_push_str("efghi")
+        });
+        assert!(result.is_err());
+        assert_eq!(b.as_str(), "ab");
+        assert_eq!(b.transaction(|b| b.try_push_str("cd")), Ok(()));
+        assert_eq!(b.as_str(), "abcd");
+    }
+
+    #[test]
+    fn test_drain_both_ends() {
+        let mut b = buf::<8>("aébc");
+        let mut d = b.drain(1..4);
+        assert_eq!(b.as_str(), "ac");
+        assert_eq!(d.next_back(), Some('b'));
+        assert_eq!(d.as_str(), "é");
+        assert_eq!(d.next(), Some('é'));
+        assert_eq!(d.next(), None);
+    }
+}
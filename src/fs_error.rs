@@ -3,7 +3,7 @@
 use super::*;
 
 /// Custom error type for `FixedStr` conversions.
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum FixedStrError {
     /// Thrown when the input exceeds the available capacity.
@@ -17,19 +17,42 @@ pub enum FixedStrError {
         found: usize,
     },
     /// Thrown when the byte content cannot be parsed as valid UTF-8.
-    InvalidUtf8,
+    ///
+    /// The fields mirror [`core::str::Utf8Error`]:
+    /// - `valid_up_to`: The byte offset of the first invalid sequence.
+    /// - `error_len`: The length of the invalid sequence, or `None` if the input ended
+    ///   in the middle of an otherwise valid sequence.
+    InvalidUtf8 {
+        /// The number of valid bytes before the invalid sequence.
+        valid_up_to: usize,
+        /// The length of the invalid sequence, or `None` for an incomplete trailing sequence.
+        error_len: Option<usize>,
+    },
+    /// Thrown by strict conversions when the input contains a null byte (`\0`),
+    /// which would otherwise silently terminate the string.
+    ///
+    /// - `position`: The byte offset of the first null byte in the input.
+    EmbeddedNull {
+        /// The byte offset of the first null byte.
+        position: usize,
+    },
+    /// Thrown when an operation could only complete by cutting the content at a
+    /// character boundary.
+    ///
+    /// - `dropped`: The number of bytes that were, or would have been, cut off.
+    Truncated {
+        /// The number of bytes cut off.
+        dropped: usize,
+    },
+    /// Thrown when a value's `Display` implementation returns an error while being formatted.
+    Format,
 }
 
-impl fmt::Debug for FixedStrError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::Overflow {
-                available: remaining,
-                found,
-            } => {
-                write!(f, "Overflow: available {}, found {}", remaining, found)
-            }
-            Self::InvalidUtf8 => write!(f, "InvalidUtf8"),
+impl From<str::Utf8Error> for FixedStrError {
+    fn from(e: str::Utf8Error) -> Self {
+        Self::InvalidUtf8 {
+            valid_up_to: e.valid_up_to(),
+            error_len: e.error_len(),
         }
     }
 }
@@ -47,7 +70,33 @@ impl fmt::Display for FixedStrError {
                     found, remaining
                 )
             }
-            Self::InvalidUtf8 => write!(f, "Invalid UTF-8"),
+            Self::InvalidUtf8 {
+                valid_up_to,
+                error_len: Some(len),
+            } => write!(
+                f,
+                "Invalid UTF-8: sequence of {} bytes at position {}",
+                len, valid_up_to
+            ),
+            Self::InvalidUtf8 {
+                valid_up_to,
+                error_len: None,
+            } => write!(
+                f,
+                "Invalid UTF-8: incomplete sequence at position {}",
+                valid_up_to
+            ),
+            Self::EmbeddedNull { position } => {
+                write!(f, "Embedded null byte at position {}", position)
+            }
+            Self::Truncated { dropped } => {
+                write!(
+                    f,
+                    "Truncated at a character boundary: {} bytes dropped",
+                    dropped
+                )
+            }
+            Self::Format => write!(f, "Formatting failed: the value returned an error"),
         }
     }
 }
@@ -71,6 +120,60 @@ fn test_error_display() {
         format!("{}", overflow_error),
         "Overflow: tried to add 5 bytes with only 2 bytes available"
     );
-    let invalid_utf8_error = FixedStrError::InvalidUtf8;
-    assert_eq!(format!("{}", invalid_utf8_error), "Invalid UTF-8");
+    assert_eq!(
+        format!("{:?}", overflow_error),
+        "Overflow { available: 2, found: 5 }"
+    );
+    let invalid_utf8_error = FixedStrError::InvalidUtf8 {
+        valid_up_to: 3,
+        error_len: Some(1),
+    };
+    assert_eq!(
+        format!("{}", invalid_utf8_error),
+        "Invalid UTF-8: sequence of 1 bytes at position 3"
+    );
+    let incomplete_error = FixedStrError::InvalidUtf8 {
+        valid_up_to: 4,
+        error_len: None,
+    };
+    assert_eq!(
+        format!("{}", incomplete_error),
+        "Invalid UTF-8: incomplete sequence at position 4"
+    );
+    let null_error = FixedStrError::EmbeddedNull { position: 3 };
+    assert_eq!(
+        format!("{}", null_error),
+        "Embedded null byte at position 3"
+    );
+    let truncated_error = FixedStrError::Truncated { dropped: 2 };
+    assert_eq!(
+        format!("{}", truncated_error),
+        "Truncated at a character boundary: 2 bytes dropped"
+    );
+    assert_eq!(
+        format!("{}", FixedStrError::Format),
+        "Formatting failed: the value returned an error"
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_error_from_utf8_error() {
+    use super::*;
+    let invalid = RawFixedStr::<6>::from_bytes([b'a', b'b', 0xFF, b'c', b'd', 0]);
+    assert_eq!(
+        invalid.try_as_str(),
+        Err(FixedStrError::InvalidUtf8 {
+            valid_up_to: 2,
+            error_len: Some(1),
+        })
+    );
+    let incomplete = RawFixedStr::<6>::from_bytes([b'a', b'b', 0xE3, 0x81, 0, 0]);
+    assert_eq!(
+        incomplete.try_as_str(),
+        Err(FixedStrError::InvalidUtf8 {
+            valid_up_to: 2,
+            error_len: None,
+        })
+    );
 }
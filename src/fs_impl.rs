@@ -41,6 +41,9 @@ impl<const N: usize> AsRef<str> for FixedStr<N> {
 }
 
 /// Implements `Borrow<str>` for `FixedStr`, returning the effective string.
+///
+/// `Hash` is implemented in terms of the same string, so `HashMap<FixedStr<N>, V>`
+/// and `HashSet<FixedStr<N>>` can be looked up by `&str`.
 impl<const N: usize> Borrow<str> for FixedStr<N> {
     fn borrow(&self) -> &str {
         self.as_str()
@@ -50,11 +53,14 @@ impl<const N: usize> Borrow<str> for FixedStr<N> {
 /// Provides a default `FixedStr` where all bytes are zero.
 impl<const N: usize> Default for FixedStr<N> {
     fn default() -> Self {
+        let () = NonZeroCapacity::<N>::ASSERT;
         Self { data: [0; N] }
     }
 }
 
 /// Deref returns a reference to the underlying byte array.
+///
+/// Wrap the value in [`FixedText`] to dereference to the effective `str` instead.
 impl<const N: usize> core::ops::Deref for FixedStr<N> {
     type Target = [u8];
     fn deref(&self) -> &Self::Target {
@@ -62,56 +68,94 @@ impl<const N: usize> core::ops::Deref for FixedStr<N> {
     }
 }
 
-/// Mutable Deref returns a mutable reference to the underlying byte array.
-impl<const N: usize> core::ops::DerefMut for FixedStr<N> {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.data
-    }
-}
-
 /// Attempts to construct a FixedStr from a byte slice using exact copy semantics.
 ///
 /// # Errors
 /// - Returns `FixedStrError::Overflow` if the effective byte count is greater than N.
 /// - Returns `FixedStrError::InvalidUtf8` if the resulting string is not valid UTF‑8.
-///
-/// # Panics
-/// Panics if `N == 0`.
 impl<const N: usize> core::convert::TryFrom<&[u8]> for FixedStr<N> {
     type Error = FixedStrError;
     fn try_from(slice: &[u8]) -> Result<Self, Self::Error> {
         let buf = copy_into_buffer(slice.effective_bytes(), BufferCopyMode::Exact)?;
-        let result = Self { data: buf };
-        if result.is_valid() {
-            Ok(result)
-        } else {
-            Err(FixedStrError::InvalidUtf8)
-        }
+        str::from_utf8(buf.effective_bytes())?;
+        Ok(Self { data: buf })
+    }
+}
+
+/// Strictly constructs a FixedStr from a &str.
+///
+/// Unlike [`FixedStr::new`], this never truncates. Use `new` when truncation is intended.
+///
+/// # Errors
+/// - Returns `FixedStrError::Overflow` if the input is longer than N bytes.
+/// - Returns `FixedStrError::EmbeddedNull` if the input contains a null byte.
+impl<const N: usize> core::convert::TryFrom<&str> for FixedStr<N> {
+    type Error = FixedStrError;
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        check_no_null(s)?;
+        let buf = copy_into_buffer(s.as_bytes(), BufferCopyMode::Exact)?;
+        Ok(Self { data: buf })
+    }
+}
+
+/// Parses a FixedStr with the same strict rules as `TryFrom<&str>`.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{FixedStr, FixedStrError};
+///
+/// let name: FixedStr<8> = "sensor".parse().unwrap();
+/// assert_eq!(name, "sensor");
+///
+/// let err = "too long for this".parse::<FixedStr<8>>().unwrap_err();
+/// assert_eq!(err, FixedStrError::Overflow { available: 8, found: 17 });
+/// ```
+impl<const N: usize> core::str::FromStr for FixedStr<N> {
+    type Err = FixedStrError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
     }
 }
 
-/// Constructs a FixedStr from a &str using the standard constructor.
+/// Collects characters into a `FixedStr`, truncating like `Extend<char>` for [`FixedStrBuf`].
+/// Use [`FixedStr::try_from_iter`] to fail on overflow instead.
 ///
-/// **Warning:** If the input contains a null byte or invalid UTF‑8, the string is truncated.
-impl<const N: usize> From<&str> for FixedStr<N> {
-    fn from(s: &str) -> Self {
-        Self::new(s)
+/// # Examples
+/// ```
+/// use fixed_str::FixedStr;
+///
+/// let upper: FixedStr<4> = "shout".chars().map(|c| c.to_ascii_uppercase()).collect();
+/// assert_eq!(upper, "SHOU");
+/// ```
+impl<const N: usize> FromIterator<char> for FixedStr<N> {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        FixedStrBuf::from_iter(iter).finalize()
     }
 }
 
-/// Constructs a FixedStr from a &str using the standard constructor.
+/// Collects string slices into a `FixedStr`, truncating like `Extend<&str>` for [`FixedStrBuf`].
+impl<'a, const N: usize> FromIterator<&'a str> for FixedStr<N> {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        FixedStrBuf::from_iter(iter).finalize()
+    }
+}
+
+/// Finalizes a `FixedStrBuf` into a `FixedStr` of the same capacity.
 ///
-/// **Warning:** If the input contains a null byte or invalid UTF‑8, the string is truncated.
+/// The built content is kept as is, and the bytes after it are zero‑padded.
 impl<const N: usize> From<FixedStrBuf<N>> for FixedStr<N> {
     fn from(buf: FixedStrBuf<N>) -> Self {
         buf.finalize()
     }
 }
 
-/// Hashes the FixedStr based only on its effective bytes (up to the first null).
+/// Hashes the FixedStr exactly like the `str` returned by [`FixedStr::as_str`].
+///
+/// This keeps hashing consistent with `Borrow<str>`, so maps keyed by `FixedStr`
+/// can be queried with a plain `&str`.
 impl<const N: usize> Hash for FixedStr<N> {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.effective_bytes().hash(state);
+        self.as_str().hash(state);
     }
 }
 
@@ -128,6 +172,17 @@ impl<const N: usize> IntoIterator for FixedStr<N> {
     }
 }
 
+/// Compares FixedStr values based on their effective bytes, consistent with `Ord` and `Hash`.
+///
+/// Use [`FixedStr::eq_fixed`] to compare with a different capacity.
+impl<const N: usize> PartialEq for FixedStr<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.effective_bytes() == other.effective_bytes()
+    }
+}
+
+impl<const N: usize> Eq for FixedStr<N> {}
+
 /// Orders FixedStr values based on their effective bytes.
 impl<const N: usize> Ord for FixedStr<N> {
     fn cmp(&self, other: &Self) -> Ordering {
@@ -135,13 +190,64 @@ impl<const N: usize> Ord for FixedStr<N> {
     }
 }
 
-/// Implements partial ordering for FixedStr.
+/// Orders FixedStr values based on their effective bytes.
+///
+/// Use [`FixedStr::cmp_fixed`] to order against a different capacity.
 impl<const N: usize> PartialOrd for FixedStr<N> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
+/// Compares a FixedStr with a str by comparing their effective bytes.
+impl<const N: usize> PartialEq<str> for FixedStr<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.effective_bytes() == other.as_bytes().effective_bytes()
+    }
+}
+
+/// Compares a str with a FixedStr.
+impl<const N: usize> PartialEq<FixedStr<N>> for str {
+    fn eq(&self, other: &FixedStr<N>) -> bool {
+        self.as_bytes().effective_bytes() == other.effective_bytes()
+    }
+}
+
+/// Orders a FixedStr against a str, like comparing two `&str`.
+impl<const N: usize> PartialOrd<str> for FixedStr<N> {
+    fn partial_cmp(&self, other: &str) -> Option<Ordering> {
+        Some(
+            self.effective_bytes()
+                .cmp(other.as_bytes().effective_bytes()),
+        )
+    }
+}
+
+/// Orders a str against a FixedStr.
+impl<const N: usize> PartialOrd<FixedStr<N>> for str {
+    fn partial_cmp(&self, other: &FixedStr<N>) -> Option<Ordering> {
+        Some(
+            self.as_bytes()
+                .effective_bytes()
+                .cmp(other.effective_bytes()),
+        )
+    }
+}
+
+/// Orders a FixedStr against a &str.
+impl<const N: usize> PartialOrd<&str> for FixedStr<N> {
+    fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+        Some(self.effective_bytes().cmp(other.effective_bytes()))
+    }
+}
+
+/// Orders a &str against a FixedStr.
+impl<const N: usize> PartialOrd<FixedStr<N>> for &str {
+    fn partial_cmp(&self, other: &FixedStr<N>) -> Option<Ordering> {
+        Some(self.effective_bytes().cmp(other.effective_bytes()))
+    }
+}
+
 /// Compares a FixedStr with a &str by comparing their effective bytes.
 impl<const N: usize> PartialEq<&str> for FixedStr<N> {
     fn eq(&self, other: &&str) -> bool {
@@ -231,9 +337,22 @@ pub mod std_ext {
         }
     }
 
-    impl<const N: usize> From<String> for FixedStr<N> {
-        fn from(s: String) -> Self {
-            Self::new(&s)
+    impl<const N: usize> PartialOrd<String> for FixedStr<N> {
+        fn partial_cmp(&self, other: &String) -> Option<Ordering> {
+            Some(self.effective_bytes().cmp(other.effective_bytes()))
+        }
+    }
+
+    impl<const N: usize> PartialOrd<FixedStr<N>> for String {
+        fn partial_cmp(&self, other: &FixedStr<N>) -> Option<Ordering> {
+            Some(self.effective_bytes().cmp(other.effective_bytes()))
+        }
+    }
+
+    impl<const N: usize> TryFrom<String> for FixedStr<N> {
+        type Error = FixedStrError;
+        fn try_from(s: String) -> Result<Self, Self::Error> {
+            Self::try_from(s.as_str())
         }
     }
 
@@ -296,26 +415,120 @@ mod impl_tests {
         assert_eq!(a, c);
     }
 
+    #[test]
+    fn test_eq_ord_ignore_trailing_bytes() {
+        // Bytes after the first null must not affect equality or ordering.
+        // SAFETY: The bytes before the first null are ASCII.
+        let a = unsafe { FixedStr::<5>::from_bytes_unsafe(*b"ab\0xx") };
+        let b = unsafe { FixedStr::<5>::from_bytes_unsafe(*b"ab\0yy") };
+        assert_eq!(a, b);
+        assert_eq!(a.cmp(&b), Ordering::Equal);
+        assert!(!a.raw_eq(&b));
+        assert_eq!(a.raw_cmp(&b), Ordering::Less);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hash_consistent_with_eq() {
+        use std::collections::{BTreeSet, HashSet};
+        // SAFETY: The bytes before the first null are ASCII.
+        let a = unsafe { FixedStr::<5>::from_bytes_unsafe(*b"ab\0xx") };
+        let b = unsafe { FixedStr::<5>::from_bytes_unsafe(*b"ab\0yy") };
+
+        let mut hashed = HashSet::new();
+        hashed.insert(a);
+        assert!(!hashed.insert(b));
+
+        let mut ordered = BTreeSet::new();
+        ordered.insert(a);
+        assert!(!ordered.insert(b));
+    }
+
     #[cfg(feature = "std")]
     #[test]
     fn test_from_string_and_into_string() {
         // Test conversion from String to FixedStr and back.
         let s = String::from("Hello");
-        let fixed: FixedStr<10> = s.clone().into();
+        let fixed: FixedStr<10> = s.clone().try_into().unwrap();
         assert_eq!(fixed.as_str(), "Hello");
         let s2: String = fixed.into();
         assert_eq!(s2, "Hello");
     }
 
+    #[test]
+    fn test_try_from_str_strict() {
+        let fixed = FixedStr::<5>::try_from("Hello").unwrap();
+        assert_eq!(fixed, "Hello");
+        assert_eq!(
+            FixedStr::<4>::try_from("Hello"),
+            Err(FixedStrError::Overflow {
+                available: 4,
+                found: 5
+            })
+        );
+        assert_eq!(
+            "ab\0c".parse::<FixedStr<8>>(),
+            Err(FixedStrError::EmbeddedNull { position: 2 })
+        );
+    }
+
     #[test]
     fn test_as_mut_bytes() {
         // Test that modifying the mutable bytes directly affects the effective string.
         let mut fixed = FixedStr::<10>::new("Hello");
         {
-            let bytes = fixed.as_mut_bytes();
+            // SAFETY: Replacing one ASCII byte with another keeps the content valid UTF‑8.
+            let bytes = unsafe { fixed.as_mut_bytes() };
             // Change the first byte from 'H' to 'J'
             bytes[0] = b'J';
         }
         assert_eq!(fixed.as_str(), "Jello");
     }
+
+    #[test]
+    fn test_as_mut_str_and_ascii_case() {
+        let mut fixed = FixedStr::<10>::new("Grüße");
+        fixed.make_ascii_uppercase();
+        assert_eq!(fixed.as_str(), "GRüßE");
+        fixed.make_ascii_lowercase();
+        assert_eq!(fixed.as_str(), "grüße");
+
+        let s = fixed.as_mut_str();
+        assert_eq!(s.len(), 7);
+        s[..2].make_ascii_uppercase();
+        assert_eq!(fixed.as_str(), "GRüße");
+    }
+
+    #[test]
+    fn test_edit_bytes_rolls_back_invalid_utf8() {
+        let mut fixed = FixedStr::<6>::new("héllo");
+        // Overwriting the continuation byte of 'é' would leave a dangling lead byte.
+        let result = fixed.edit_bytes(|b| b[2] = b'x');
+        assert_eq!(
+            result,
+            Err(FixedStrError::InvalidUtf8 {
+                valid_up_to: 1,
+                error_len: Some(1),
+            })
+        );
+        assert_eq!(fixed.as_str(), "héllo");
+
+        // Writing a null byte simply shortens the string.
+        assert_eq!(fixed.edit_bytes(|b| b[1] = 0), Ok(()));
+        assert_eq!(fixed.as_str(), "h");
+        // The rest of 'é' and "llo" are zeroed rather than left behind the null.
+        assert_eq!(fixed.as_bytes(), b"h\0\0\0\0\0");
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let fixed: FixedStr<6> = ["ab", "cd", "ef", "gh"].into_iter().collect();
+        assert_eq!(fixed, "abcdef");
+        let fixed: FixedStr<3> = "x€y".chars().collect();
+        assert_eq!(fixed, "x");
+        assert_eq!(
+            FixedStr::<3>::try_from_iter(["a", "b"]),
+            Ok(FixedStr::new("ab"))
+        );
+    }
 }
@@ -0,0 +1,195 @@
+// fixed_str/src/fs_iter.rs
+
+use super::*;
+
+/// Owned iterators for `FixedStr`.
+///
+/// These take the string by value, so the iterators can outlive the original binding.
+/// For the borrowing `str` iterators, use [`FixedStr::as_str`] or [`FixedText`].
+impl<const N: usize> FixedStr<N> {
+    /// Returns an owned iterator over the characters of the effective string.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::{Chars, FixedStr};
+    ///
+    /// fn reversed(s: FixedStr<8>) -> Chars<8> {
+    ///     s.chars()
+    /// }
+    ///
+    /// let chars: Vec<char> = reversed(FixedStr::new("añb")).rev().collect();
+    /// assert_eq!(chars, ['b', 'ñ', 'a']);
+    /// ```
+    pub fn chars(self) -> Chars<N> {
+        let back = self.len();
+        Chars {
+            text: self,
+            front: 0,
+            back,
+        }
+    }
+
+    /// Returns an owned iterator over the characters of the effective string and their byte offsets.
+    pub fn char_indices(self) -> CharIndices<N> {
+        CharIndices {
+            chars: self.chars(),
+        }
+    }
+
+    /// Returns an owned iterator over the effective bytes, the same as `into_iter()`.
+    pub fn bytes(self) -> EffectiveBytesIter<N> {
+        self.into_iter()
+    }
+}
+
+/// Owned iterators for `FixedStrBuf`, matching those of [`FixedStr`].
+impl<const N: usize> FixedStrBuf<N> {
+    /// Returns an owned iterator over the characters of the effective string.
+    pub fn chars(self) -> Chars<N> {
+        self.finalize().chars()
+    }
+
+    /// Returns an owned iterator over the characters of the effective string and their byte offsets.
+    pub fn char_indices(self) -> CharIndices<N> {
+        self.finalize().char_indices()
+    }
+
+    /// Returns an owned iterator over the effective bytes, the same as `into_iter()`.
+    pub fn bytes(self) -> EffectiveBytesIter<N> {
+        self.into_iter()
+    }
+}
+
+//******************************************************************************
+//  Iterators
+//******************************************************************************
+
+/// An owned iterator over the characters of a [`FixedStr`] or [`FixedStrBuf`].
+///
+/// Created by [`FixedStr::chars`] and [`FixedStrBuf::chars`].
+#[derive(Clone, Debug)]
+pub struct Chars<const N: usize> {
+    text: FixedStr<N>,
+    /// Byte offset of the next character from the front.
+    front: usize,
+    /// Byte offset just past the next character from the back, starting at the effective length.
+    back: usize,
+}
+
+impl<const N: usize> Chars<N> {
+    /// Returns the characters that have not been yielded yet.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: `back` was the effective length when the iterator was built, and both ends
+        // only ever move by whole characters, so the range is valid UTF‑8.
+        unsafe { str::from_utf8_unchecked(&self.text.data[self.front..self.back]) }
+    }
+}
+
+impl<const N: usize> Iterator for Chars<N> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next()?;
+        self.front += ch.len_utf8();
+        Some(ch)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let bytes = self.back - self.front;
+        ((bytes + 3) / 4, Some(bytes))
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for Chars<N> {
+    fn next_back(&mut self) -> Option<char> {
+        let ch = self.as_str().chars().next_back()?;
+        self.back -= ch.len_utf8();
+        Some(ch)
+    }
+}
+
+impl<const N: usize> core::iter::FusedIterator for Chars<N> {}
+
+/// An owned iterator over the characters of a [`FixedStr`] or [`FixedStrBuf`] and their
+/// byte offsets.
+///
+/// Created by [`FixedStr::char_indices`] and [`FixedStrBuf::char_indices`].
+#[derive(Clone, Debug)]
+pub struct CharIndices<const N: usize> {
+    chars: Chars<N>,
+}
+
+impl<const N: usize> CharIndices<N> {
+    /// Returns the characters that have not been yielded yet.
+    pub fn as_str(&self) -> &str {
+        self.chars.as_str()
+    }
+}
+
+impl<const N: usize> Iterator for CharIndices<N> {
+    type Item = (usize, char);
+
+    fn next(&mut self) -> Option<(usize, char)> {
+        let index = self.chars.front;
+        self.chars.next().map(|ch| (index, ch))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chars.size_hint()
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for CharIndices<N> {
+    fn next_back(&mut self) -> Option<(usize, char)> {
+        let ch = self.chars.next_back()?;
+        Some((self.chars.back, ch))
+    }
+}
+
+impl<const N: usize> core::iter::FusedIterator for CharIndices<N> {}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod iter_tests {
+    use super::*;
+
+    #[test]
+    fn test_chars_both_ends() {
+        let fs = FixedStr::<8>::new("aé€");
+        let mut chars = fs.chars();
+        assert_eq!(chars.size_hint(), (2, Some(6)));
+        assert_eq!(chars.next(), Some('a'));
+        assert_eq!(chars.next_back(), Some('€'));
+        assert_eq!(chars.as_str(), "é");
+        assert_eq!(chars.next(), Some('é'));
+        assert_eq!(chars.next(), None);
+        assert_eq!(chars.next_back(), None);
+    }
+
+    #[test]
+    fn test_char_indices() {
+        let mut buf = FixedStrBuf::<8>::new();
+        buf.try_push_str("xé!").unwrap();
+        let mut it = buf.char_indices();
+        assert_eq!(it.next_back(), Some((3, '!')));
+        assert_eq!(it.next(), Some((0, 'x')));
+        assert_eq!(it.next(), Some((1, 'é')));
+        assert_eq!(it.next(), None);
+    }
+
+    #[test]
+    fn test_bytes_exact_size() {
+        let fs = FixedStr::<6>::new("abc");
+        let mut bytes = fs.bytes();
+        assert_eq!(bytes.len(), 3);
+        assert_eq!(bytes.next_back(), Some(b'c'));
+        assert_eq!(bytes.next(), Some(b'a'));
+        assert_eq!(bytes.len(), 1);
+
+        let buf = FixedStrBuf::from(fs);
+        assert!(buf.bytes().eq(fs.bytes()));
+    }
+}
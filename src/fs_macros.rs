@@ -0,0 +1,178 @@
+// fixed_str/src/fs_macros.rs
+
+/// Formats arguments directly into a `FixedStr<N>`, returning an error instead of truncating.
+///
+/// The capacity is given first, followed by the usual `format!` arguments. Expands to
+/// [`FixedStr::try_from_display`](crate::FixedStr::try_from_display), so nothing is allocated.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{fixed_format, FixedStrError};
+///
+/// let (host, port) = ("db01", 5432);
+/// let addr = fixed_format!(16; "{}:{}", host, port).unwrap();
+/// assert_eq!(addr, "db01:5432");
+///
+/// let err = fixed_format!(4; "{}:{}", host, port).unwrap_err();
+/// assert_eq!(err, FixedStrError::Overflow { available: 4, found: 9 });
+/// ```
+#[macro_export]
+macro_rules! fixed_format {
+    ($n:expr; $($arg:tt)*) => {
+        $crate::FixedStr::<{ $n }>::try_from_display(&::core::format_args!($($arg)*))
+    };
+}
+
+/// Formats arguments directly into a `FixedStr<N>`, truncating at the last whole character that fits.
+///
+/// Expands to [`FixedStr::from_display`](crate::FixedStr::from_display).
+///
+/// # Examples
+/// ```
+/// use fixed_str::fixed_format_lossy;
+///
+/// let temp = 21.456;
+/// let label = fixed_format_lossy!(8; "temp={:.1}°C", temp);
+/// assert_eq!(label, "temp=21.");
+/// ```
+#[macro_export]
+macro_rules! fixed_format_lossy {
+    ($n:expr; $($arg:tt)*) => {
+        $crate::FixedStr::<{ $n }>::from_display(&::core::format_args!($($arg)*))
+    };
+}
+
+/// Creates a `FixedStr` from a string literal, checked at compile time.
+///
+/// With only a literal, the capacity is inferred as the literal's byte length (at least 1).
+/// With an explicit capacity (`fixed_str!(N; "...")`), the literal must fit in `N` bytes.
+/// In both forms a literal that is too long or contains a null byte fails to compile,
+/// since the value is built by [`FixedStr::from_literal`](crate::FixedStr::from_literal)
+/// in a const item.
+///
+/// The explicit capacity must be a constant expression that does not depend on generic parameters.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{fixed_str, FixedStr};
+///
+/// let exact = fixed_str!("GET");
+/// assert_eq!(exact.capacity(), 3);
+///
+/// let padded: FixedStr<8> = fixed_str!(8; "GET");
+/// assert_eq!(padded, "GET");
+/// ```
+///
+/// ```compile_fail
+/// use fixed_str::fixed_str;
+///
+/// let too_long = fixed_str!(2; "GET");
+/// ```
+///
+/// ```compile_fail
+/// use fixed_str::fixed_str;
+///
+/// let with_null = fixed_str!("GE\0T");
+/// ```
+#[macro_export]
+macro_rules! fixed_str {
+    ($lit:expr) => {{
+        const LITERAL: &str = $lit;
+        const FIXED: $crate::FixedStr<
+            {
+                if LITERAL.len() == 0 {
+                    1
+                } else {
+                    LITERAL.len()
+                }
+            },
+        > = $crate::FixedStr::from_literal(LITERAL);
+        FIXED
+    }};
+    ($n:expr; $lit:expr) => {{
+        const FIXED: $crate::FixedStr<{ $n }> = $crate::FixedStr::from_literal($lit);
+        FIXED
+    }};
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod macro_tests {
+    use crate::*;
+
+    #[test]
+    fn test_fixed_format_fits() {
+        let id = 42;
+        let fs = fixed_format!(8; "id-{:04}", id).unwrap();
+        assert_eq!(fs, "id-0042");
+        assert_eq!(fs.capacity(), 8);
+    }
+
+    #[test]
+    fn test_fixed_format_overflow_reports_full_length() {
+        let word = "héllo";
+        let err = fixed_format!(4; "[{}]", word).unwrap_err();
+        assert_eq!(
+            err,
+            FixedStrError::Overflow {
+                available: 4,
+                found: 8
+            }
+        );
+    }
+
+    #[test]
+    fn test_fixed_format_lossy_respects_char_boundaries() {
+        let word = "héllo";
+        // "[h" fits, "é" would straddle the capacity of 3.
+        assert_eq!(fixed_format_lossy!(3; "[{}]", word), "[h");
+    }
+
+    #[test]
+    fn test_fixed_str_infers_capacity() {
+        let fs = fixed_str!("héllo");
+        assert_eq!(fs.capacity(), 6);
+        assert_eq!(fs, "héllo");
+
+        let empty = fixed_str!("");
+        assert_eq!(empty.capacity(), 1);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_fixed_str_explicit_capacity() {
+        const CAP: usize = 16;
+        let fs = fixed_str!(CAP; "sensor/temp");
+        assert_eq!(fs.capacity(), CAP);
+        assert_eq!(fs, "sensor/temp");
+    }
+
+    #[test]
+    fn test_try_from_display_rejects_null() {
+        let s = "a\0b";
+        assert_eq!(
+            FixedStr::<8>::try_from_display(s),
+            Err(FixedStrError::EmbeddedNull { position: 1 })
+        );
+    }
+
+    #[test]
+    fn test_try_from_display_reports_display_error() {
+        struct Failing;
+
+        impl core::fmt::Display for Failing {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("partial")?;
+                Err(core::fmt::Error)
+            }
+        }
+
+        assert_eq!(
+            FixedStr::<16>::try_from_display(&Failing),
+            Err(FixedStrError::Format)
+        );
+    }
+}
@@ -0,0 +1,425 @@
+// fixed_str/src/fs_policy.rs
+
+use super::*;
+use core::marker::PhantomData;
+
+/// Decides how a [`PolicyStr`] stores input that does not fit into its capacity.
+///
+/// The crate provides [`Truncate`], [`Reject`], [`Panic`], and [`Ellipsis`]. Every way of
+/// putting a string into a `PolicyStr` (`try_new`, `set`, `TryFrom`, `FromStr`, and serde)
+/// goes through [`OverflowPolicy::store`], so a field type behaves the same on every path.
+/// Operations that build their output piecewise, such as [`FixedStr::replace`], go through
+/// [`OverflowPolicy::resolve`].
+pub trait OverflowPolicy {
+    /// Finishes an output that was built piecewise and cut at `N` bytes.
+    ///
+    /// `output` holds the longest prefix that fit, ending at the first null byte if there was
+    /// one, and `report` describes what was dropped.
+    fn resolve<const N: usize>(
+        output: FixedStrBuf<N>,
+        report: Truncation,
+    ) -> Result<FixedStr<N>, FixedStrError>;
+
+    /// Stores `input` into a `FixedStr<N>`, or returns the error that rejected it.
+    ///
+    /// The default implementation appends `input` lossily and passes the result to
+    /// [`resolve`](Self::resolve).
+    fn store<const N: usize>(input: &str) -> Result<FixedStr<N>, FixedStrError> {
+        let mut output = FixedStrBuf::new();
+        let report = output.push_str_lossy_with_report(input);
+        Self::resolve(output, report)
+    }
+}
+
+/// Marks policies whose [`OverflowPolicy::store`] never returns an error.
+///
+/// These policies enable the infallible [`PolicyStr::new`] and [`PolicyStr::set_infallible`].
+/// The trait is sealed: only [`Truncate`], [`Panic`], and [`Ellipsis`] implement it, so a
+/// custom policy cannot claim to be infallible and then fail:
+///
+/// ```compile_fail
+/// use fixed_str::{InfalliblePolicy, OverflowPolicy, Reject};
+///
+/// struct Strict;
+///
+/// impl OverflowPolicy for Strict {
+///     fn resolve<const N: usize>(
+///         output: fixed_str::FixedStrBuf<N>,
+///         report: fixed_str::Truncation,
+///     ) -> Result<fixed_str::FixedStr<N>, fixed_str::FixedStrError> {
+///         Reject::resolve(output, report)
+///     }
+/// }
+///
+/// impl InfalliblePolicy for Strict {}
+/// ```
+pub trait InfalliblePolicy: OverflowPolicy + sealed::Sealed {}
+
+mod sealed {
+    /// Restricts [`InfalliblePolicy`](super::InfalliblePolicy) to the policies of this crate.
+    pub trait Sealed {}
+
+    impl Sealed for super::Truncate {}
+    impl Sealed for super::Panic {}
+    impl Sealed for super::Ellipsis {}
+}
+
+/// Truncates overflowing input at the last UTF‑8 boundary, like [`FixedStr::new`].
+///
+/// A null byte (`\0`) in the input ends the string.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Truncate;
+
+impl OverflowPolicy for Truncate {
+    fn resolve<const N: usize>(
+        output: FixedStrBuf<N>,
+        _report: Truncation,
+    ) -> Result<FixedStr<N>, FixedStrError> {
+        Ok(output.finalize())
+    }
+
+    fn store<const N: usize>(input: &str) -> Result<FixedStr<N>, FixedStrError> {
+        Ok(FixedStr::new(input))
+    }
+}
+
+impl InfalliblePolicy for Truncate {}
+
+/// Rejects overflowing input, like `FixedStr`'s `TryFrom<&str>`.
+///
+/// Input containing a null byte (`\0`) is rejected with `FixedStrError::EmbeddedNull`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Reject;
+
+impl OverflowPolicy for Reject {
+    fn resolve<const N: usize>(
+        output: FixedStrBuf<N>,
+        report: Truncation,
+    ) -> Result<FixedStr<N>, FixedStrError> {
+        let found = output.len() + report.dropped_bytes;
+        if report.stopped_at_nul {
+            return Err(FixedStrError::EmbeddedNull { position: found });
+        }
+        if report.is_truncated() {
+            return Err(FixedStrError::Overflow {
+                available: N,
+                found,
+            });
+        }
+        Ok(output.finalize())
+    }
+
+    fn store<const N: usize>(input: &str) -> Result<FixedStr<N>, FixedStrError> {
+        FixedStr::try_from(input)
+    }
+}
+
+/// Panics on input that [`Reject`] would refuse.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Panic;
+
+impl OverflowPolicy for Panic {
+    #[track_caller]
+    fn resolve<const N: usize>(
+        output: FixedStrBuf<N>,
+        report: Truncation,
+    ) -> Result<FixedStr<N>, FixedStrError> {
+        match Reject::resolve(output, report) {
+            Ok(fixed) => Ok(fixed),
+            Err(e) => panic!("output does not fit into FixedStr<{}>: {}", N, e),
+        }
+    }
+
+    #[track_caller]
+    fn store<const N: usize>(input: &str) -> Result<FixedStr<N>, FixedStrError> {
+        match FixedStr::try_from(input) {
+            Ok(fixed) => Ok(fixed),
+            Err(e) => panic!("input does not fit into FixedStr<{}>: {}", N, e),
+        }
+    }
+}
+
+impl InfalliblePolicy for Panic {}
+
+/// Truncates overflowing input and marks the cut with `"..."`.
+///
+/// The dots replace the last bytes that fit, cut back to a character boundary. If `N` is
+/// smaller than three, only as many dots as fit are written. A null byte (`\0`) in the input
+/// ends the string, as with [`Truncate`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Ellipsis;
+
+impl Ellipsis {
+    const MARKER: &'static str = "...";
+}
+
+impl OverflowPolicy for Ellipsis {
+    fn resolve<const N: usize>(
+        mut output: FixedStrBuf<N>,
+        report: Truncation,
+    ) -> Result<FixedStr<N>, FixedStrError> {
+        if report.is_truncated() {
+            let marker = &Self::MARKER[..Self::MARKER.len().min(N)];
+            output.truncate(N - marker.len());
+            output.push_str_lossy(marker);
+        }
+        Ok(output.finalize())
+    }
+}
+
+impl InfalliblePolicy for Ellipsis {}
+
+/// A [`FixedStr<N>`] whose overflow behavior is fixed by the policy type `P`.
+///
+/// `PolicyStr` has the same layout as `FixedStr<N>` and dereferences to it for read access.
+/// Every way of storing a string goes through `P`, so protocol structs can mix strict and
+/// lossy fields and have the type system keep them apart.
+///
+/// # Examples
+/// ```
+/// use fixed_str::{FixedStrError, PolicyStr, Reject, Truncate};
+///
+/// struct Header {
+///     topic: PolicyStr<8, Reject>,
+///     comment: PolicyStr<8, Truncate>,
+/// }
+///
+/// let header = Header {
+///     topic: PolicyStr::try_new("sensors").unwrap(),
+///     comment: PolicyStr::new("a long comment"),
+/// };
+/// assert_eq!(header.topic, "sensors");
+/// assert_eq!(header.comment, "a long c");
+///
+/// let topic = PolicyStr::<8, Reject>::try_new("sensors/temperature");
+/// assert_eq!(topic, Err(FixedStrError::Overflow { available: 8, found: 19 }));
+/// ```
+#[repr(transparent)]
+pub struct PolicyStr<const N: usize, P: OverflowPolicy = Truncate> {
+    inner: FixedStr<N>,
+    _policy: PhantomData<P>,
+}
+
+impl<const N: usize, P: OverflowPolicy> PolicyStr<N, P> {
+    /// Stores `input` according to the policy `P`.
+    ///
+    /// # Errors
+    /// Returns the error produced by `P`, such as `FixedStrError::Overflow` for [`Reject`].
+    pub fn try_new(input: &str) -> Result<Self, FixedStrError> {
+        P::store(input).map(Self::from_fixed)
+    }
+
+    /// Wraps an existing `FixedStr`, which always fits.
+    pub const fn from_fixed(inner: FixedStr<N>) -> Self {
+        Self {
+            inner,
+            _policy: PhantomData,
+        }
+    }
+
+    /// Replaces the content with `input` according to the policy `P`.
+    ///
+    /// # Errors
+    /// Returns the error produced by `P`; the current content is left unchanged.
+    pub fn set(&mut self, input: &str) -> Result<(), FixedStrError> {
+        self.inner = P::store(input)?;
+        Ok(())
+    }
+
+    /// Returns the wrapped `FixedStr`.
+    pub const fn as_fixed_str(&self) -> &FixedStr<N> {
+        &self.inner
+    }
+
+    /// Consumes the `PolicyStr` and returns the wrapped `FixedStr`.
+    pub const fn into_inner(self) -> FixedStr<N> {
+        self.inner
+    }
+}
+
+impl<const N: usize, P: InfalliblePolicy> PolicyStr<N, P> {
+    /// Stores `input` according to a policy that cannot fail.
+    pub fn new(input: &str) -> Self {
+        match Self::try_new(input) {
+            Ok(s) => s,
+            Err(_) => unreachable!("infallible overflow policy returned an error"),
+        }
+    }
+
+    /// Replaces the content with `input` according to a policy that cannot fail.
+    pub fn set_infallible(&mut self, input: &str) {
+        *self = Self::new(input);
+    }
+}
+
+//******************************************************************************
+//  Implementations
+//******************************************************************************
+
+impl<const N: usize, P: OverflowPolicy> Clone for PolicyStr<N, P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const N: usize, P: OverflowPolicy> Copy for PolicyStr<N, P> {}
+
+impl<const N: usize, P: OverflowPolicy> fmt::Debug for PolicyStr<N, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl<const N: usize, P: OverflowPolicy> fmt::Display for PolicyStr<N, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl<const N: usize, P: OverflowPolicy> Default for PolicyStr<N, P> {
+    fn default() -> Self {
+        Self::from_fixed(FixedStr::default())
+    }
+}
+
+/// Gives read access to the wrapped `FixedStr`. There is no `DerefMut`, since writes
+/// must go through the policy.
+impl<const N: usize, P: OverflowPolicy> core::ops::Deref for PolicyStr<N, P> {
+    type Target = FixedStr<N>;
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<const N: usize, P: OverflowPolicy> AsRef<str> for PolicyStr<N, P> {
+    fn as_ref(&self) -> &str {
+        self.inner.as_str()
+    }
+}
+
+impl<const N: usize, P: OverflowPolicy> Borrow<str> for PolicyStr<N, P> {
+    fn borrow(&self) -> &str {
+        self.inner.as_str()
+    }
+}
+
+impl<const N: usize, P: OverflowPolicy> From<FixedStr<N>> for PolicyStr<N, P> {
+    fn from(inner: FixedStr<N>) -> Self {
+        Self::from_fixed(inner)
+    }
+}
+
+impl<const N: usize, P: OverflowPolicy> From<PolicyStr<N, P>> for FixedStr<N> {
+    fn from(s: PolicyStr<N, P>) -> Self {
+        s.inner
+    }
+}
+
+/// Stores a string according to the policy `P`.
+impl<const N: usize, P: OverflowPolicy> core::convert::TryFrom<&str> for PolicyStr<N, P> {
+    type Error = FixedStrError;
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Self::try_new(input)
+    }
+}
+
+/// Parses a string according to the policy `P`.
+impl<const N: usize, P: OverflowPolicy> core::str::FromStr for PolicyStr<N, P> {
+    type Err = FixedStrError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::try_new(input)
+    }
+}
+
+impl<const N: usize, P: OverflowPolicy> Hash for PolicyStr<N, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.inner.hash(state);
+    }
+}
+
+impl<const N: usize, P: OverflowPolicy> PartialEq for PolicyStr<N, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<const N: usize, P: OverflowPolicy> Eq for PolicyStr<N, P> {}
+
+impl<const N: usize, P: OverflowPolicy> Ord for PolicyStr<N, P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.inner.cmp(&other.inner)
+    }
+}
+
+impl<const N: usize, P: OverflowPolicy> PartialOrd for PolicyStr<N, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize, P: OverflowPolicy> PartialEq<FixedStr<N>> for PolicyStr<N, P> {
+    fn eq(&self, other: &FixedStr<N>) -> bool {
+        self.inner == *other
+    }
+}
+
+impl<const N: usize, P: OverflowPolicy> PartialEq<str> for PolicyStr<N, P> {
+    fn eq(&self, other: &str) -> bool {
+        self.inner.as_str() == other
+    }
+}
+
+impl<const N: usize, P: OverflowPolicy> PartialEq<&str> for PolicyStr<N, P> {
+    fn eq(&self, other: &&str) -> bool {
+        self.inner.as_str() == *other
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod policy_tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_policy() {
+        let mut s = PolicyStr::<5>::new("Hello, World!");
+        assert_eq!(s, "Hello");
+        assert_eq!(s.set("Hi\0there"), Ok(()));
+        assert_eq!(s, "Hi");
+    }
+
+    #[test]
+    fn test_reject_policy() {
+        let mut s = PolicyStr::<5, Reject>::try_new("Hello").unwrap();
+        assert_eq!(
+            s.set("Hello!"),
+            Err(FixedStrError::Overflow {
+                available: 5,
+                found: 6
+            })
+        );
+        assert_eq!(s, "Hello");
+        assert_eq!(
+            "a\0b".parse::<PolicyStr<5, Reject>>(),
+            Err(FixedStrError::EmbeddedNull { position: 1 })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit")]
+    fn test_panic_policy() {
+        let _ = PolicyStr::<4, Panic>::new("too long");
+    }
+
+    #[test]
+    fn test_ellipsis_policy() {
+        assert_eq!(PolicyStr::<8, Ellipsis>::new("fits"), "fits");
+        assert_eq!(PolicyStr::<8, Ellipsis>::new("overflowing"), "overf...");
+        // The cut moves back so that "é" is not split before the marker.
+        assert_eq!(PolicyStr::<6, Ellipsis>::new("abé123"), "ab...");
+        assert_eq!(PolicyStr::<2, Ellipsis>::new("abc"), "..");
+    }
+}
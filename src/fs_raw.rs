@@ -0,0 +1,276 @@
+// fixed_str/src/fs_raw.rs
+
+use super::*;
+
+/// A fixed–length, null–padded byte record with no UTF‑8 guarantee.
+///
+/// `RawFixedStr<N>` has the same layout as [`FixedStr<N>`] (a transparent `[u8; N]`) and the same
+/// null‑terminator semantics, but accepts arbitrary bytes. Use it for data read off the wire and
+/// convert it into a `FixedStr` with `TryFrom` once it has been validated.
+///
+/// Equality, ordering, and hashing operate on the effective bytes (up to the first null).
+///
+/// # Examples
+/// ```
+/// use fixed_str::{FixedStr, RawFixedStr};
+///
+/// let raw = RawFixedStr::<6>::from_bytes(*b"temp\0\0");
+/// let name = FixedStr::try_from(raw).unwrap();
+/// assert_eq!(name, "temp");
+///
+/// let garbage = RawFixedStr::<4>::from_bytes([b'o', b'k', 0xFF, 0]);
+/// assert!(FixedStr::try_from(garbage).is_err());
+/// ```
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct RawFixedStr<const N: usize> {
+    pub(super) data: [u8; N],
+}
+
+impl<const N: usize> RawFixedStr<N> {
+    /// Returns the maximum capacity of the `RawFixedStr`.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of bytes before the first null byte.
+    pub fn len(&self) -> usize {
+        find_first_null(&self.data)
+    }
+
+    /// Returns whether the effective content is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the effective bytes (up to the first null byte) form a valid UTF‑8 string.
+    pub fn is_valid(&self) -> bool {
+        self.try_as_str().is_ok()
+    }
+
+    //****************************************************************************
+    //  Constructors
+    //****************************************************************************
+
+    /// Stores a byte array as‑is.
+    pub const fn from_bytes(bytes: [u8; N]) -> Self {
+        let () = NonZeroCapacity::<N>::ASSERT;
+        Self { data: bytes }
+    }
+
+    /// Copies up to `N` bytes from a slice, zero‑padding the remainder.
+    ///
+    /// Bytes beyond the capacity are discarded without regard to UTF‑8 boundaries.
+    pub fn from_slice(slice: &[u8]) -> Self {
+        Self {
+            data: copy_into_buffer(slice, BufferCopyMode::Slice).unwrap(),
+        }
+    }
+
+    //****************************************************************************
+    //  Accessors
+    //****************************************************************************
+
+    /// Attempts to interpret the effective bytes as a UTF‑8 string.
+    ///
+    /// Returns an error if the data up to the first null byte is not valid UTF‑8.
+    pub fn try_as_str(&self) -> Result<&str, FixedStrError> {
+        Ok(str::from_utf8(self.effective_bytes())?)
+    }
+
+    /// Returns the raw byte array, including any bytes after the first null.
+    pub const fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the raw byte array as mutable.
+    ///
+    /// Any byte values are allowed, since a `RawFixedStr` makes no UTF‑8 guarantee.
+    pub fn as_mut_bytes(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+
+    /// Consumes the `RawFixedStr` and returns the underlying byte array.
+    pub const fn into_bytes(self) -> [u8; N] {
+        self.data
+    }
+
+    /// Compares the full underlying buffers, including any bytes after the first null.
+    pub fn raw_eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+
+    /// Orders two values by their full underlying buffers, including any bytes after the first null.
+    pub fn raw_cmp(&self, other: &Self) -> Ordering {
+        self.data.cmp(&other.data)
+    }
+
+    /// Converts the effective bytes to an owned `String` in a lossy manner,
+    /// replacing any invalid UTF‑8 sequences with the Unicode replacement character.
+    #[cfg(feature = "std")]
+    pub fn to_string_lossy(&self) -> String {
+        String::from_utf8_lossy(self.effective_bytes()).into_owned()
+    }
+}
+
+//******************************************************************************
+//  Implementations
+//******************************************************************************
+
+/// Prints the effective string if it is valid UTF‑8, or a hex dump of the underlying data otherwise.
+impl<const N: usize> fmt::Debug for RawFixedStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.try_as_str() {
+            Ok(s) => write!(f, "{:?}", s),
+            Err(_) => write!(
+                f,
+                "<invalid UTF-8>\n{:?}",
+                fast_format_hex::<384>(&self.data, 16, Some(8))
+            ),
+        }
+    }
+}
+
+impl<const N: usize> EffectiveBytes for RawFixedStr<N> {
+    fn effective_bytes(&self) -> &[u8] {
+        self.data.effective_bytes()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for RawFixedStr<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl<const N: usize> Default for RawFixedStr<N> {
+    fn default() -> Self {
+        Self::from_bytes([0; N])
+    }
+}
+
+/// Wraps a validated `FixedStr` as a raw record; this never fails.
+impl<const N: usize> From<FixedStr<N>> for RawFixedStr<N> {
+    fn from(fixed: FixedStr<N>) -> Self {
+        Self { data: fixed.data }
+    }
+}
+
+/// Validates a raw record as a `FixedStr`.
+///
+/// The buffer is kept as‑is, including any bytes after the first null.
+///
+/// # Errors
+/// Returns `FixedStrError::InvalidUtf8` if the effective bytes are not valid UTF‑8.
+impl<const N: usize> core::convert::TryFrom<RawFixedStr<N>> for FixedStr<N> {
+    type Error = FixedStrError;
+    fn try_from(raw: RawFixedStr<N>) -> Result<Self, Self::Error> {
+        raw.try_as_str()?;
+        Ok(Self { data: raw.data })
+    }
+}
+
+impl<const N: usize> Hash for RawFixedStr<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.effective_bytes().hash(state);
+    }
+}
+
+impl<const N: usize> PartialEq for RawFixedStr<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.effective_bytes() == other.effective_bytes()
+    }
+}
+
+impl<const N: usize> Eq for RawFixedStr<N> {}
+
+impl<const N: usize> Ord for RawFixedStr<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.effective_bytes().cmp(other.effective_bytes())
+    }
+}
+
+impl<const N: usize> PartialOrd for RawFixedStr<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> PartialEq<[u8]> for RawFixedStr<N> {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.effective_bytes() == other.effective_bytes()
+    }
+}
+
+impl<const N: usize> PartialEq<&[u8]> for RawFixedStr<N> {
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.effective_bytes() == other.effective_bytes()
+    }
+}
+
+impl<const N: usize> PartialEq<[u8; N]> for RawFixedStr<N> {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.effective_bytes() == other.effective_bytes()
+    }
+}
+
+impl<const N: usize> PartialEq<FixedStr<N>> for RawFixedStr<N> {
+    fn eq(&self, other: &FixedStr<N>) -> bool {
+        self.effective_bytes() == other.effective_bytes()
+    }
+}
+
+impl<const N: usize> PartialEq<RawFixedStr<N>> for FixedStr<N> {
+    fn eq(&self, other: &RawFixedStr<N>) -> bool {
+        self.effective_bytes() == other.effective_bytes()
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod raw_tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_accepts_invalid_utf8() {
+        let raw = RawFixedStr::<5>::from_bytes([b'a', 0xFF, b'b', 0, 0]);
+        assert_eq!(raw.len(), 3);
+        assert!(!raw.is_valid());
+        assert_eq!(raw, &b"a\xFFb"[..]);
+        assert_eq!(
+            FixedStr::try_from(raw),
+            Err(FixedStrError::InvalidUtf8 {
+                valid_up_to: 1,
+                error_len: Some(1),
+            })
+        );
+    }
+
+    #[test]
+    fn test_raw_roundtrip_through_fixed_str() {
+        let fixed = FixedStr::<8>::new("wire");
+        let raw = RawFixedStr::from(fixed);
+        assert_eq!(raw, fixed);
+        assert_eq!(FixedStr::try_from(raw), Ok(fixed));
+    }
+
+    #[test]
+    fn test_raw_from_slice_cuts_at_capacity() {
+        // The slice is cut at N bytes even in the middle of a character.
+        let raw = RawFixedStr::<2>::from_slice("é!".as_bytes());
+        assert_eq!(raw.as_bytes(), "é".as_bytes());
+        let raw = RawFixedStr::<1>::from_slice("é".as_bytes());
+        assert!(!raw.is_valid());
+    }
+
+    #[test]
+    fn test_raw_eq_ignores_trailing_bytes() {
+        let a = RawFixedStr::<5>::from_bytes(*b"ab\0\xFFx");
+        let b = RawFixedStr::<5>::from_bytes(*b"ab\0yy");
+        assert_eq!(a, b);
+        assert!(!a.raw_eq(&b));
+    }
+}
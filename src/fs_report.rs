@@ -0,0 +1,100 @@
+// fixed_str/src/fs_report.rs
+
+use super::*;
+
+/// Describes what a lossy operation discarded from its input.
+///
+/// Returned by the `*_with_report` variants of the truncating constructors and setters, such as
+/// [`FixedStr::new_with_report`] and [`FixedStrBuf::push_str_lossy_with_report`].
+///
+/// The input is first cut at its first null byte (`\0`), just like the plain lossy operations do.
+/// That cut is reported through [`stopped_at_nul`](Self::stopped_at_nul) only; the dropped counts
+/// cover the remaining bytes that did not fit, so null padding never counts as truncation.
+///
+/// # Examples
+/// ```
+/// use fixed_str::FixedStr;
+///
+/// let (fs, report) = FixedStr::<4>::new_with_report("héllo");
+/// assert_eq!(fs, "hél");
+/// assert!(report.is_truncated());
+/// assert_eq!(report.dropped_bytes, 2);
+/// assert_eq!(report.dropped_chars, 2);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Truncation {
+    /// The number of input bytes before the first null that were not stored.
+    pub dropped_bytes: usize,
+    /// The number of characters in the dropped bytes.
+    ///
+    /// For byte input that is not valid UTF‑8, each byte that is not a UTF‑8 continuation byte
+    /// is counted as one character.
+    pub dropped_chars: usize,
+    /// Whether the input contained a null byte, which ended it early.
+    pub stopped_at_nul: bool,
+    /// Whether the cut discarded a partial or invalid UTF‑8 sequence, either because a multi‑byte
+    /// character did not fit or because the byte input was not valid UTF‑8.
+    pub incomplete_utf8: bool,
+}
+
+impl Truncation {
+    /// Returns `true` if any input before the first null byte was dropped.
+    pub fn is_truncated(&self) -> bool {
+        self.dropped_bytes > 0
+    }
+
+    /// Builds the report for an input of which `kept` effective bytes were stored
+    /// in a space of `available` bytes.
+    pub(crate) fn measure(input: &[u8], kept: usize, available: usize) -> Self {
+        let effective = input.effective_bytes();
+        let dropped = &effective[kept..];
+        Self {
+            dropped_bytes: dropped.len(),
+            dropped_chars: dropped.iter().filter(|&&b| (b & 0xC0) != 0x80).count(),
+            stopped_at_nul: effective.len() < input.len(),
+            incomplete_utf8: kept < effective.len().min(available),
+        }
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod report_tests {
+    use super::*;
+
+    #[test]
+    fn test_report_fits() {
+        let report = Truncation::measure(b"abc", 3, 5);
+        assert_eq!(report, Truncation::default());
+        assert!(!report.is_truncated());
+    }
+
+    #[test]
+    fn test_report_cut_at_char_boundary() {
+        let report = Truncation::measure(b"abcdef", 4, 4);
+        assert_eq!(report.dropped_bytes, 2);
+        assert_eq!(report.dropped_chars, 2);
+        assert!(!report.incomplete_utf8);
+        assert!(!report.stopped_at_nul);
+    }
+
+    #[test]
+    fn test_report_split_char() {
+        // Only 'a' fits into 2 bytes; the 3-byte '€' is dropped whole.
+        let report = Truncation::measure("a€".as_bytes(), 1, 2);
+        assert_eq!(report.dropped_bytes, 3);
+        assert_eq!(report.dropped_chars, 1);
+        assert!(report.incomplete_utf8);
+    }
+
+    #[test]
+    fn test_report_nul_is_not_truncation() {
+        let report = Truncation::measure(b"ab\0\0\0", 2, 8);
+        assert!(report.stopped_at_nul);
+        assert!(!report.is_truncated());
+    }
+}
@@ -0,0 +1,450 @@
+// fixed_str/src/fs_search.rs
+
+use super::*;
+
+/// A pattern that can be searched for in a [`FixedStr`].
+///
+/// This mirrors the unstable `core::str::pattern::Pattern` for the pattern types that are
+/// needed most: `char`, `&str`, and `FnMut(char) -> bool` predicates. All positions are byte
+/// offsets into the effective string.
+pub trait FixedPattern {
+    /// Returns the byte range of the first match in `haystack`.
+    fn next_match(&mut self, haystack: &str) -> Option<(usize, usize)>;
+
+    /// Returns the byte range of the last match in `haystack`.
+    fn next_match_back(&mut self, haystack: &str) -> Option<(usize, usize)>;
+
+    /// Returns the length of the match at the start of `haystack`, if there is one.
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize>;
+
+    /// Returns the length of the match at the end of `haystack`, if there is one.
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize>;
+}
+
+impl FixedPattern for char {
+    fn next_match(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        haystack.find(*self).map(|i| (i, i + self.len_utf8()))
+    }
+
+    fn next_match_back(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        haystack.rfind(*self).map(|i| (i, i + self.len_utf8()))
+    }
+
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack.starts_with(*self).then(|| self.len_utf8())
+    }
+
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack.ends_with(*self).then(|| self.len_utf8())
+    }
+}
+
+impl FixedPattern for &str {
+    fn next_match(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        haystack.find(*self).map(|i| (i, i + self.len()))
+    }
+
+    fn next_match_back(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        haystack.rfind(*self).map(|i| (i, i + self.len()))
+    }
+
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack.starts_with(*self).then(|| self.len())
+    }
+
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack.ends_with(*self).then(|| self.len())
+    }
+}
+
+impl<F: FnMut(char) -> bool> FixedPattern for F {
+    fn next_match(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        haystack
+            .char_indices()
+            .find(|&(_, c)| self(c))
+            .map(|(i, c)| (i, i + c.len_utf8()))
+    }
+
+    fn next_match_back(&mut self, haystack: &str) -> Option<(usize, usize)> {
+        haystack
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| self(c))
+            .map(|(i, c)| (i, i + c.len_utf8()))
+    }
+
+    fn prefix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack
+            .chars()
+            .next()
+            .filter(|&c| self(c))
+            .map(char::len_utf8)
+    }
+
+    fn suffix_len(&mut self, haystack: &str) -> Option<usize> {
+        haystack
+            .chars()
+            .next_back()
+            .filter(|&c| self(c))
+            .map(char::len_utf8)
+    }
+}
+
+//******************************************************************************
+//  Search and Replace
+//******************************************************************************
+
+/// Allocation‑free searching and rewriting on the effective string.
+///
+/// These work like the `str` methods of the same names, except that
+/// `strip_prefix` and `strip_suffix` edit the string in place instead of returning a slice.
+impl<const N: usize> FixedStr<N> {
+    /// Returns the byte index of the first match of `pat`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let fs = FixedStr::<16>::new("key=value");
+    /// assert_eq!(fs.find('='), Some(3));
+    /// assert_eq!(fs.find("val"), Some(4));
+    /// assert_eq!(fs.find(char::is_uppercase), None);
+    /// ```
+    pub fn find<P: FixedPattern>(&self, mut pat: P) -> Option<usize> {
+        pat.next_match(self.as_str()).map(|(start, _)| start)
+    }
+
+    /// Returns the byte index of the last match of `pat`.
+    pub fn rfind<P: FixedPattern>(&self, mut pat: P) -> Option<usize> {
+        pat.next_match_back(self.as_str()).map(|(start, _)| start)
+    }
+
+    /// Returns `true` if `pat` matches anywhere in the string.
+    pub fn contains<P: FixedPattern>(&self, pat: P) -> bool {
+        self.find(pat).is_some()
+    }
+
+    /// Returns `true` if `pat` matches at the start of the string.
+    pub fn starts_with<P: FixedPattern>(&self, mut pat: P) -> bool {
+        pat.prefix_len(self.as_str()).is_some()
+    }
+
+    /// Returns `true` if `pat` matches at the end of the string.
+    pub fn ends_with<P: FixedPattern>(&self, mut pat: P) -> bool {
+        pat.suffix_len(self.as_str()).is_some()
+    }
+
+    /// Removes one match of `pat` from the start of the string, shifting the rest of the
+    /// content to the front and zeroing the freed bytes.
+    ///
+    /// Returns `true` if a prefix was removed.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let mut fs = FixedStr::<16>::new("/dev/tty");
+    /// assert!(fs.strip_prefix("/dev/"));
+    /// assert_eq!(fs, "tty");
+    /// assert!(!fs.strip_prefix('/'));
+    /// ```
+    pub fn strip_prefix<P: FixedPattern>(&mut self, mut pat: P) -> bool {
+        let len = self.len();
+        match pat.prefix_len(self.as_str()) {
+            Some(cut) => {
+                self.data.copy_within(cut..len, 0);
+                self.data[len - cut..len].fill(0);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes one match of `pat` from the end of the string, zeroing the freed bytes.
+    ///
+    /// Returns `true` if a suffix was removed.
+    pub fn strip_suffix<P: FixedPattern>(&mut self, mut pat: P) -> bool {
+        let len = self.len();
+        match pat.suffix_len(self.as_str()) {
+            Some(cut) => {
+                self.data[len - cut..len].fill(0);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns a copy of the string with every match of `from` replaced by `to`.
+    ///
+    /// The result is built without allocating and finished under `policy` if it does not fit
+    /// into `N` bytes. As with `str::replace`, an empty pattern matches between every character.
+    ///
+    /// # Errors
+    /// Returns the error produced by `policy`, for example `FixedStrError::Overflow` for
+    /// [`Reject`].
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::{FixedStr, FixedStrError, Reject, Truncate};
+    ///
+    /// let fs = FixedStr::<8>::new("a-b-c");
+    /// assert_eq!(fs.replace('-', "::", Truncate).unwrap(), "a::b::c");
+    /// assert_eq!(
+    ///     fs.replace('-', "<->", Reject),
+    ///     Err(FixedStrError::Overflow { available: 8, found: 9 })
+    /// );
+    /// ```
+    pub fn replace<P: FixedPattern, Pol: OverflowPolicy>(
+        &self,
+        from: P,
+        to: &str,
+        policy: Pol,
+    ) -> Result<Self, FixedStrError> {
+        self.replacen(from, to, usize::MAX, policy)
+    }
+
+    /// Returns a copy of the string with the first `count` matches of `from` replaced by `to`.
+    ///
+    /// See [`replace`](Self::replace) for how overflow is handled.
+    pub fn replacen<P: FixedPattern, Pol: OverflowPolicy>(
+        &self,
+        mut from: P,
+        to: &str,
+        count: usize,
+        _policy: Pol,
+    ) -> Result<Self, FixedStrError> {
+        let mut output = FixedStrBuf::<N>::new();
+        let mut writer = output.lossy_writer();
+        let mut rest = self.as_str();
+        let mut replaced = 0;
+        while replaced < count {
+            let (start, end) = match from.next_match(rest) {
+                Some(range) => range,
+                None => break,
+            };
+            let _ = writer.write_str(&rest[..start]);
+            let _ = writer.write_str(to);
+            replaced += 1;
+            if start == end {
+                // An empty match: keep the next character and search after it.
+                match rest[end..].chars().next() {
+                    Some(c) => {
+                        let next = end + c.len_utf8();
+                        let _ = writer.write_str(&rest[end..next]);
+                        rest = &rest[next..];
+                    }
+                    None => {
+                        rest = "";
+                        break;
+                    }
+                }
+            } else {
+                rest = &rest[end..];
+            }
+        }
+        let _ = writer.write_str(rest);
+        let report = writer.report();
+        Pol::resolve(output, report)
+    }
+
+    //****************************************************************************
+    //  Splitting
+    //****************************************************************************
+
+    /// Splits the string on `sep` into up to `K` fields of capacity `M`, without allocating.
+    ///
+    /// Fields are produced like `str::split`; the first `K` are stored and any remaining slots
+    /// are left empty. Each field longer than `M` bytes is truncated at the last UTF‑8 boundary,
+    /// as with [`FixedStr::new`].
+    ///
+    /// Returns the fields and the total number of fields in the input, which is greater than
+    /// `K` if some were dropped. Use [`try_split_into`](Self::try_split_into) to fail instead
+    /// of truncating or dropping fields.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStr;
+    ///
+    /// let frame = FixedStr::<32>::new("GPGGA,123519,4807.038,N");
+    /// let (fields, count) = frame.split_into::<8, 3>(',');
+    /// assert_eq!(count, 4);
+    /// assert_eq!(fields[0], "GPGGA");
+    /// assert_eq!(fields[2], "4807.038");
+    /// ```
+    pub fn split_into<const M: usize, const K: usize>(
+        &self,
+        sep: impl FixedPattern,
+    ) -> ([FixedStr<M>; K], usize) {
+        let mut fields = [FixedStr::<M>::default(); K];
+        let mut count = 0;
+        self.for_each_field(sep, |field| {
+            if let Some(slot) = fields.get_mut(count) {
+                *slot = FixedStr::new(field);
+            }
+            count += 1;
+        });
+        (fields, count)
+    }
+
+    /// Splits the string on `sep` into up to `K` fields of capacity `M`, failing instead of
+    /// losing data.
+    ///
+    /// Fields are produced like [`split_into`](Self::split_into), and unused slots are left
+    /// empty. Returns the fields and the number of fields in the input.
+    ///
+    /// # Errors
+    /// - Returns `FixedStrError::Overflow` with byte counts for the first field longer than `M`
+    ///   bytes.
+    /// - Returns `FixedStrError::Overflow { available: K, found }` if the input has more than
+    ///   `K` fields; here both values count fields.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::{FixedStr, FixedStrError};
+    ///
+    /// let frame = FixedStr::<32>::new("GPGGA,123519,N");
+    /// let (fields, count) = frame.try_split_into::<8, 4>(',').unwrap();
+    /// assert_eq!((fields[1], count), (FixedStr::new("123519"), 3));
+    /// assert_eq!(
+    ///     frame.try_split_into::<8, 2>(','),
+    ///     Err(FixedStrError::Overflow { available: 2, found: 3 })
+    /// );
+    /// ```
+    pub fn try_split_into<const M: usize, const K: usize>(
+        &self,
+        sep: impl FixedPattern,
+    ) -> Result<([FixedStr<M>; K], usize), FixedStrError> {
+        let mut fields = [FixedStr::<M>::default(); K];
+        let mut count = 0;
+        let mut error = None;
+        self.for_each_field(sep, |field| {
+            if let (None, Some(slot)) = (error, fields.get_mut(count)) {
+                match FixedStr::try_from(field) {
+                    Ok(fixed) => *slot = fixed,
+                    Err(e) => error = Some(e),
+                }
+            }
+            count += 1;
+        });
+        if let Some(e) = error {
+            return Err(e);
+        }
+        if count > K {
+            return Err(FixedStrError::Overflow {
+                available: K,
+                found: count,
+            });
+        }
+        Ok((fields, count))
+    }
+
+    /// Calls `store` with each field of the string split on `sep`, like `str::split`.
+    fn for_each_field(&self, mut sep: impl FixedPattern, mut store: impl FnMut(&str)) {
+        let haystack = self.as_str();
+        let mut field_start = 0;
+        let mut search = 0;
+        while let Some((start, end)) = sep.next_match(&haystack[search..]) {
+            let (start, end) = (search + start, search + end);
+            store(&haystack[field_start..start]);
+            field_start = end;
+            search = end;
+            if start == end {
+                // An empty match: search again after the next character.
+                match haystack[end..].chars().next() {
+                    Some(c) => search += c.len_utf8(),
+                    None => break,
+                }
+            }
+        }
+        store(&haystack[field_start..]);
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod search_tests {
+    use super::*;
+
+    #[test]
+    fn test_find_with_patterns() {
+        let fs = FixedStr::<16>::new("aé-bé");
+        assert_eq!(fs.find('é'), Some(1));
+        assert_eq!(fs.rfind('é'), Some(5));
+        assert_eq!(fs.find("-b"), Some(3));
+        assert_eq!(fs.rfind(|c: char| c.is_ascii_alphabetic()), Some(4));
+        assert!(fs.contains('-'));
+        assert!(!fs.contains('\0'));
+        assert!(fs.starts_with("aé"));
+        assert!(fs.ends_with(|c: char| !c.is_ascii()));
+    }
+
+    #[test]
+    fn test_strip_in_place() {
+        let mut fs = FixedStr::<8>::new("[[tag]]");
+        assert!(fs.strip_prefix('['));
+        assert!(fs.strip_suffix("]]"));
+        assert_eq!(fs, "[tag");
+        assert_eq!(fs.as_bytes()[4..], [0u8; 4]);
+        assert!(fs.strip_prefix(|c: char| c == '['));
+        assert_eq!(fs, "tag");
+        assert!(!fs.strip_suffix('x'));
+    }
+
+    #[test]
+    fn test_split_into_fields() {
+        let fs = FixedStr::<32>::new("a,bb,,dddd");
+        let (fields, count) = fs.split_into::<3, 5>(',');
+        assert_eq!(count, 4);
+        assert_eq!(fields[1], "bb");
+        assert_eq!(fields[2], "");
+        // Fields longer than M are truncated; unused slots stay empty.
+        assert_eq!(fields[3], "ddd");
+        assert_eq!(fields[4], "");
+
+        let (fields, count) = FixedStr::<8>::new("ab").split_into::<4, 4>("");
+        assert_eq!(count, 4);
+        assert_eq!(fields, ["", "a", "b", ""].map(FixedStr::<4>::new));
+
+        let (_, count) = FixedStr::<8>::new("").split_into::<4, 2>(' ');
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn test_try_split_into_reports_loss() {
+        let fs = FixedStr::<32>::new("a,bb,,dddd");
+        let (fields, count) = fs.try_split_into::<4, 5>(',').unwrap();
+        assert_eq!(count, 4);
+        assert_eq!(fields[3], "dddd");
+        assert_eq!(
+            fs.try_split_into::<3, 5>(','),
+            Err(FixedStrError::Overflow {
+                available: 3,
+                found: 4,
+            })
+        );
+        assert_eq!(
+            fs.try_split_into::<4, 3>(','),
+            Err(FixedStrError::Overflow {
+                available: 3,
+                found: 4,
+            })
+        );
+    }
+
+    #[test]
+    fn test_replace_under_policies() {
+        let fs = FixedStr::<8>::new("a.b.c");
+        assert_eq!(fs.replacen('.', "", 1, Truncate).unwrap(), "ab.c");
+        assert_eq!(fs.replace('.', "...", Truncate).unwrap(), "a...b...");
+        assert_eq!(fs.replace('.', "...", Ellipsis).unwrap(), "a...b...");
+        assert_eq!(fs.replace('.', "....", Ellipsis).unwrap(), "a.......");
+        assert!(fs.replace('.', "\0", Reject).is_err());
+        assert_eq!(
+            FixedStr::<8>::new("ab").replace("", "-", Reject).unwrap(),
+            "-a-b-"
+        );
+    }
+}
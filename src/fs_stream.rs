@@ -0,0 +1,454 @@
+// fixed_str/src/fs_stream.rs
+
+use super::*;
+
+/// A [`FixedStrBuf`] filled from a byte stream, decoding UTF‑8 incrementally.
+///
+/// Text read from a UART or socket arrives in arbitrary chunks, so a multi‑byte character may be
+/// split across two reads. [`push_bytes`](Self::push_bytes) validates each chunk and holds back an
+/// incomplete trailing sequence (at most 3 bytes) until the next call completes it.
+/// When the stream ends, [`finish`](Self::finish) reports a sequence that was never completed.
+///
+/// The buffer is only written through the stream, so held‑back bytes always belong at its end.
+/// It dereferences to the `FixedStrBuf` for read access; take it with
+/// [`into_inner`](Self::into_inner) once the stream is finished.
+///
+/// As with [`FixedStrBuf::try_push_str`], a null byte (`\0`) ends the input: it and
+/// everything after it in the same chunk are ignored.
+#[derive(Clone, Copy, Debug)]
+pub struct FixedStrStream<const N: usize> {
+    buf: FixedStrBuf<N>,
+    /// An incomplete UTF‑8 sequence carried over between calls to `push_bytes`.
+    pending: [u8; 3],
+    /// The number of bytes used in `pending`.
+    pending_len: usize,
+}
+
+impl<const N: usize> FixedStrStream<N> {
+    /// Creates a stream that writes into a new, empty buffer.
+    pub const fn new() -> Self {
+        Self::from_buf(FixedStrBuf::new())
+    }
+
+    /// Creates a stream that appends to the content of `buf`.
+    pub const fn from_buf(buf: FixedStrBuf<N>) -> Self {
+        Self {
+            buf,
+            pending: [0u8; 3],
+            pending_len: 0,
+        }
+    }
+
+    /// Appends a chunk of UTF‑8 bytes, carrying an incomplete trailing sequence into the next call.
+    ///
+    /// The chunk is validated before anything is written, so on error the buffer and the
+    /// carried‑over bytes are left unchanged.
+    ///
+    /// # Errors
+    /// - Returns `FixedStrError::InvalidUtf8` if the chunk contains an invalid sequence. Its offsets
+    ///   count from the start of the carried‑over bytes (see [`pending_bytes`](Self::pending_bytes)),
+    ///   followed by the chunk.
+    /// - Returns `FixedStrError::Overflow` if the complete characters do not fit.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStrStream;
+    ///
+    /// let mut stream = FixedStrStream::<8>::new();
+    /// // "é" is split across two reads.
+    /// stream.push_bytes(b"caf\xC3").unwrap();
+    /// assert_eq!(stream.as_str(), "caf");
+    /// stream.push_bytes(b"\xA9!").unwrap();
+    /// assert_eq!(stream.as_str(), "café!");
+    /// stream.finish().unwrap();
+    /// ```
+    pub fn push_bytes(&mut self, bytes: &[u8]) -> Result<(), FixedStrError> {
+        let pending = self.pending;
+        let pending = &pending[..self.pending_len];
+        let bytes = until_first_null(bytes);
+
+        let mut error = None;
+        let mut found = 0;
+        decode_utf8(pending, bytes, |piece| match piece {
+            Ok(s) => {
+                found += s.effective_bytes().len();
+                true
+            }
+            Err((valid_up_to, len)) => {
+                error = Some(FixedStrError::InvalidUtf8 {
+                    valid_up_to,
+                    error_len: Some(len),
+                });
+                false
+            }
+        });
+        if let Some(e) = error {
+            return Err(e);
+        }
+        if found > self.buf.remaining() {
+            return Err(FixedStrError::Overflow {
+                available: self.buf.remaining(),
+                found,
+            });
+        }
+
+        let buf = &mut self.buf;
+        let (tail, tail_len) = decode_utf8(pending, bytes, |piece| {
+            if let Ok(s) = piece {
+                let pushed = buf.try_push_str(s);
+                debug_assert!(pushed.is_ok());
+            }
+            true
+        });
+        self.pending = tail;
+        self.pending_len = tail_len;
+        Ok(())
+    }
+
+    /// Appends a chunk of bytes, replacing each invalid UTF‑8 sequence with `U+FFFD`.
+    ///
+    /// An incomplete trailing sequence is carried into the next call, as with
+    /// [`push_bytes`](Self::push_bytes). If the output does not fit, as many whole characters as
+    /// possible are appended, the rest of the chunk is dropped, and `false` is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use fixed_str::FixedStrStream;
+    ///
+    /// let mut stream = FixedStrStream::<8>::new();
+    /// assert!(stream.push_bytes_lossy(b"a\xFFb\xE2\x82"));
+    /// assert_eq!(stream.as_str(), "a\u{FFFD}b");
+    /// assert!(stream.finish_lossy());
+    /// assert_eq!(stream.as_str(), "a\u{FFFD}b\u{FFFD}");
+    /// ```
+    pub fn push_bytes_lossy(&mut self, bytes: &[u8]) -> bool {
+        let pending = self.pending;
+        let pending = &pending[..self.pending_len];
+        let bytes = until_first_null(bytes);
+
+        let buf = &mut self.buf;
+        let mut fit = true;
+        let (tail, tail_len) = decode_utf8(pending, bytes, |piece| {
+            fit = buf.push_str_lossy(piece.unwrap_or("\u{FFFD}"));
+            fit
+        });
+        self.pending = tail;
+        self.pending_len = tail_len;
+        fit
+    }
+
+    /// Returns the bytes of an incomplete UTF‑8 sequence held back for the next
+    /// [`push_bytes`](Self::push_bytes) call.
+    pub fn pending_bytes(&self) -> &[u8] {
+        &self.pending[..self.pending_len]
+    }
+
+    /// Ends the byte stream, discarding an incomplete sequence left over from
+    /// [`push_bytes`](Self::push_bytes).
+    ///
+    /// # Errors
+    /// Returns `FixedStrError::InvalidUtf8` with `valid_up_to: 0` and `error_len: None` if an
+    /// incomplete sequence was discarded.
+    pub fn finish(&mut self) -> Result<(), FixedStrError> {
+        if self.pending_len == 0 {
+            return Ok(());
+        }
+        self.pending_len = 0;
+        Err(FixedStrError::InvalidUtf8 {
+            valid_up_to: 0,
+            error_len: None,
+        })
+    }
+
+    /// Ends the byte stream, appending `U+FFFD` in place of an incomplete sequence.
+    ///
+    /// Returns `false` if the replacement character did not fit.
+    pub fn finish_lossy(&mut self) -> bool {
+        if self.pending_len == 0 {
+            return true;
+        }
+        self.pending_len = 0;
+        self.buf.push_str_lossy("\u{FFFD}")
+    }
+
+    /// Returns the buffer, dropping any held‑back bytes.
+    ///
+    /// Call [`finish`](Self::finish) first to find out whether the stream was cut short.
+    pub fn into_inner(self) -> FixedStrBuf<N> {
+        self.buf
+    }
+}
+
+impl<const N: usize> Default for FixedStrStream<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Gives read access to the buffer. There is no `DerefMut`, since appending directly would
+/// place text before the held‑back bytes.
+impl<const N: usize> core::ops::Deref for FixedStrStream<N> {
+    type Target = FixedStrBuf<N>;
+    fn deref(&self) -> &Self::Target {
+        &self.buf
+    }
+}
+
+impl<const N: usize> From<FixedStrBuf<N>> for FixedStrStream<N> {
+    fn from(buf: FixedStrBuf<N>) -> Self {
+        Self::from_buf(buf)
+    }
+}
+
+/// Cuts `bytes` just after its first null byte, if any.
+///
+/// The null is kept so that a partial sequence before it is reported as invalid
+/// instead of being carried over.
+fn until_first_null(bytes: &[u8]) -> &[u8] {
+    let nul = find_first_null(bytes);
+    &bytes[..(nul + 1).min(bytes.len())]
+}
+
+/// Returns the encoded length announced by the lead byte of a partial UTF‑8 sequence.
+fn sequence_len(lead: u8) -> usize {
+    if (lead & 0xE0) == 0xC0 {
+        2
+    } else if (lead & 0xF0) == 0xE0 {
+        3
+    } else {
+        4
+    }
+}
+
+/// Splits `pending` followed by `bytes` into valid runs and invalid sequences.
+///
+/// `emit` receives each valid run as `Ok(&str)` and each invalid sequence as
+/// `Err((offset, len))`, with offsets counted from the start of `pending`. It returns `false`
+/// to stop early. Returns the incomplete trailing sequence and its length, or an empty tail if
+/// `emit` stopped.
+fn decode_utf8<F>(pending: &[u8], mut bytes: &[u8], mut emit: F) -> ([u8; 3], usize)
+where
+    F: FnMut(Result<&str, (usize, usize)>) -> bool,
+{
+    let mut tail = [0u8; 3];
+    let mut offset = pending.len();
+
+    if !pending.is_empty() {
+        let taken = (sequence_len(pending[0]) - pending.len()).min(bytes.len());
+        let mut joined = [0u8; 4];
+        joined[..pending.len()].copy_from_slice(pending);
+        joined[pending.len()..pending.len() + taken].copy_from_slice(&bytes[..taken]);
+        let joined = &joined[..pending.len() + taken];
+        match str::from_utf8(joined) {
+            Ok(s) => {
+                if !emit(Ok(s)) {
+                    return (tail, 0);
+                }
+                bytes = &bytes[taken..];
+                offset += taken;
+            }
+            Err(e) => match e.error_len() {
+                None => {
+                    tail[..joined.len()].copy_from_slice(joined);
+                    return (tail, joined.len());
+                }
+                Some(len) => {
+                    if !emit(Err((0, len))) {
+                        return (tail, 0);
+                    }
+                    // The held‑back bytes are a valid prefix, so the invalid sequence spans all of them.
+                    bytes = &bytes[len - pending.len()..];
+                    offset += len - pending.len();
+                }
+            },
+        }
+    }
+
+    loop {
+        let e = match str::from_utf8(bytes) {
+            Ok(s) => {
+                if !s.is_empty() {
+                    emit(Ok(s));
+                }
+                return (tail, 0);
+            }
+            Err(e) => e,
+        };
+        let (valid, rest) = bytes.split_at(e.valid_up_to());
+        if !valid.is_empty() {
+            // SAFETY: `from_utf8` validated everything up to `valid_up_to`.
+            let s = unsafe { str::from_utf8_unchecked(valid) };
+            if !emit(Ok(s)) {
+                return (tail, 0);
+            }
+        }
+        match e.error_len() {
+            None => {
+                tail[..rest.len()].copy_from_slice(rest);
+                return (tail, rest.len());
+            }
+            Some(len) => {
+                if !emit(Err((offset + valid.len(), len))) {
+                    return (tail, 0);
+                }
+                bytes = &rest[len..];
+                offset += valid.len() + len;
+            }
+        }
+    }
+}
+
+//******************************************************************************
+//  std Implementations
+//******************************************************************************
+
+/// Writes bytes through [`FixedStrStream::push_bytes`], so `io::copy` can fill a fixed buffer.
+///
+/// Each `write` is all‑or‑nothing. Invalid UTF‑8 fails with `ErrorKind::InvalidData` and a chunk
+/// that does not fit fails with `ErrorKind::WriteZero`; both carry the `FixedStrError`.
+/// `flush` does not end the stream; call [`FixedStrStream::finish`] afterwards to detect a
+/// truncated final character.
+///
+/// # Examples
+/// ```
+/// use fixed_str::FixedStrStream;
+///
+/// let mut input: &[u8] = "grüße".as_bytes();
+/// let mut stream = FixedStrStream::<16>::new();
+/// std::io::copy(&mut input, &mut stream).unwrap();
+/// stream.finish().unwrap();
+/// assert_eq!(stream.into_inner().as_str(), "grüße");
+/// ```
+#[cfg(feature = "std")]
+impl<const N: usize> std::io::Write for FixedStrStream<N> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        use std::io::{Error, ErrorKind};
+        match self.push_bytes(buf) {
+            Ok(()) => Ok(buf.len()),
+            Err(e @ FixedStrError::Overflow { .. }) => Err(Error::new(ErrorKind::WriteZero, e)),
+            Err(e) => Err(Error::new(ErrorKind::InvalidData, e)),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod stream_tests {
+    use super::*;
+
+    #[test]
+    fn test_push_bytes_split_across_calls() {
+        let mut buf = FixedStrStream::<8>::new();
+        let bytes = "a€".as_bytes();
+        for b in bytes {
+            buf.push_bytes(core::slice::from_ref(b)).unwrap();
+        }
+        assert_eq!(buf.as_str(), "a€");
+        assert!(buf.pending_bytes().is_empty());
+        assert_eq!(buf.finish(), Ok(()));
+    }
+
+    #[test]
+    fn test_push_bytes_rejects_invalid() {
+        let mut buf = FixedStrStream::<8>::new();
+        buf.push_bytes(b"ab\xE2\x82").unwrap();
+        assert_eq!(buf.pending_bytes(), b"\xE2\x82");
+
+        // The carried-over sequence is broken by 'c'; offsets start at the held-back bytes.
+        let err = buf.push_bytes(b"c").unwrap_err();
+        assert_eq!(
+            err,
+            FixedStrError::InvalidUtf8 {
+                valid_up_to: 0,
+                error_len: Some(2),
+            }
+        );
+        let err = buf.push_bytes(b"\xACd\xFF").unwrap_err();
+        assert_eq!(
+            err,
+            FixedStrError::InvalidUtf8 {
+                valid_up_to: 4,
+                error_len: Some(1),
+            }
+        );
+        assert_eq!(buf.as_str(), "ab");
+        assert_eq!(buf.pending_bytes(), b"\xE2\x82");
+
+        // A null byte ends the chunk, so the sequence before it can never complete.
+        assert!(buf.push_bytes(b"\0").is_err());
+    }
+
+    #[test]
+    fn test_push_bytes_overflow_is_atomic() {
+        let mut buf = FixedStrStream::<4>::new();
+        buf.push_bytes(b"ab").unwrap();
+        assert_eq!(
+            buf.push_bytes("c€".as_bytes()),
+            Err(FixedStrError::Overflow {
+                available: 2,
+                found: 4,
+            })
+        );
+        assert_eq!(buf.as_str(), "ab");
+        // An incomplete tail does not count against the capacity until it completes.
+        buf.push_bytes(b"cd\xE2").unwrap();
+        assert_eq!(buf.as_str(), "abcd");
+        assert!(buf.finish().is_err());
+        assert!(buf.pending_bytes().is_empty());
+    }
+
+    #[test]
+    fn test_push_bytes_stops_at_null() {
+        let mut buf = FixedStrStream::<8>::new();
+        buf.push_bytes(b"ab\0\xFF").unwrap();
+        assert_eq!(buf.as_str(), "ab");
+        assert_eq!(buf.len(), 2);
+    }
+
+    #[test]
+    fn test_push_bytes_lossy() {
+        let mut buf = FixedStrStream::<8>::new();
+        assert!(buf.push_bytes_lossy(b"\xF0\x9F"));
+        assert!(buf.push_bytes_lossy(b"x"));
+        assert_eq!(buf.as_str(), "\u{FFFD}x");
+
+        let mut buf = FixedStrStream::<4>::new();
+        assert!(!buf.push_bytes_lossy(b"a\xFFbc\xE2"));
+        assert_eq!(buf.as_str(), "a\u{FFFD}");
+        assert!(buf.pending_bytes().is_empty());
+        assert!(buf.finish_lossy());
+    }
+
+    #[test]
+    fn test_from_buf_appends_after_content() {
+        let mut buf = FixedStrBuf::<8>::new();
+        buf.try_push_str("x").unwrap();
+        let mut stream = FixedStrStream::from(buf);
+        stream.push_bytes(b"\xC3").unwrap();
+        stream.push_bytes(b"\xA9").unwrap();
+        stream.finish().unwrap();
+        assert_eq!(stream.into_inner().as_str(), "xé");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_io_write() {
+        use std::io::{ErrorKind, Write};
+
+        let mut buf = FixedStrStream::<4>::new();
+        buf.write_all(b"ab\xC3").unwrap();
+        buf.write_all(b"\xA9").unwrap();
+        assert_eq!(buf.as_str(), "abé");
+        let err = buf.write_all(b"xy").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::WriteZero);
+        let err = buf.write_all(b"\xFF").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+}
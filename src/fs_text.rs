@@ -0,0 +1,169 @@
+// fixed_str/src/fs_text.rs
+
+use super::*;
+
+/// A `FixedStr` that dereferences to its effective `str` instead of the raw buffer.
+///
+/// `FixedStr` keeps `Deref<Target = [u8]>` over all `N` bytes, including the padding.
+/// `FixedText` is the opt‑in alternative: it has no inherent methods that share a name with a
+/// `str` method, so `len()`, `as_bytes()`, `chars()`, `contains()`, and the rest behave exactly as
+/// on `str` and never see the padding. The full buffer is reached explicitly through
+/// [`FixedText::as_fixed_str`].
+///
+/// # Examples
+/// ```
+/// use fixed_str::FixedText;
+///
+/// let text = FixedText::<16>::new("key=value");
+/// assert_eq!(text.len(), 9);
+/// assert_eq!(text.as_bytes(), b"key=value");
+/// assert_eq!(text.split('=').nth(1), Some("value"));
+/// assert_eq!(text.as_fixed_str().as_bytes().len(), 16);
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FixedText<const N: usize> {
+    inner: FixedStr<N>,
+}
+
+impl<const N: usize> FixedText<N> {
+    /// Creates a value from `input`, truncating like [`FixedStr::new`].
+    pub fn new(input: &str) -> Self {
+        Self::from_fixed(FixedStr::new(input))
+    }
+
+    /// Wraps an existing `FixedStr`.
+    pub const fn from_fixed(inner: FixedStr<N>) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the wrapped `FixedStr`, which gives access to the full buffer and the `FixedStr` API.
+    pub const fn as_fixed_str(&self) -> &FixedStr<N> {
+        &self.inner
+    }
+
+    /// Consumes the value and returns the wrapped `FixedStr`.
+    pub const fn into_inner(self) -> FixedStr<N> {
+        self.inner
+    }
+}
+
+//******************************************************************************
+//  Implementations
+//******************************************************************************
+
+impl<const N: usize> fmt::Debug for FixedText<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl<const N: usize> fmt::Display for FixedText<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+/// Dereferences to the effective string, so all of `str`'s methods are available.
+impl<const N: usize> core::ops::Deref for FixedText<N> {
+    type Target = str;
+    fn deref(&self) -> &Self::Target {
+        self.inner.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for FixedText<N> {
+    fn as_ref(&self) -> &str {
+        self.inner.as_str()
+    }
+}
+
+/// Borrows as the effective string; `Hash` agrees, so maps can be queried with `&str`.
+impl<const N: usize> Borrow<str> for FixedText<N> {
+    fn borrow(&self) -> &str {
+        self.inner.as_str()
+    }
+}
+
+impl<const N: usize> EffectiveBytes for FixedText<N> {
+    fn effective_bytes(&self) -> &[u8] {
+        self.inner.effective_bytes()
+    }
+}
+
+impl<const N: usize> From<FixedStr<N>> for FixedText<N> {
+    fn from(inner: FixedStr<N>) -> Self {
+        Self::from_fixed(inner)
+    }
+}
+
+impl<const N: usize> From<FixedText<N>> for FixedStr<N> {
+    fn from(text: FixedText<N>) -> Self {
+        text.inner
+    }
+}
+
+/// Strictly creates a value with the same rules as `FixedStr`'s `TryFrom<&str>`.
+impl<const N: usize> core::convert::TryFrom<&str> for FixedText<N> {
+    type Error = FixedStrError;
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        FixedStr::try_from(input).map(Self::from_fixed)
+    }
+}
+
+/// Parses a value with the same strict rules as `TryFrom<&str>`.
+impl<const N: usize> core::str::FromStr for FixedText<N> {
+    type Err = FixedStrError;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        Self::try_from(input)
+    }
+}
+
+impl<const N: usize> PartialEq<FixedStr<N>> for FixedText<N> {
+    fn eq(&self, other: &FixedStr<N>) -> bool {
+        self.inner == *other
+    }
+}
+
+impl<const N: usize> PartialEq<str> for FixedText<N> {
+    fn eq(&self, other: &str) -> bool {
+        **self == *other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for FixedText<N> {
+    fn eq(&self, other: &&str) -> bool {
+        **self == **other
+    }
+}
+
+//******************************************************************************
+//  Tests
+//******************************************************************************
+
+#[cfg(test)]
+mod text_tests {
+    use super::*;
+
+    #[test]
+    fn test_str_methods_see_effective_content() {
+        let text = FixedText::<8>::new("aé");
+        assert_eq!(text.len(), 3);
+        assert_eq!(text.as_bytes(), "aé".as_bytes());
+        assert_eq!(text.chars().next_back(), Some('é'));
+        assert!(text.eq_ignore_ascii_case("Aé"));
+        assert!(!text.contains('\0'));
+        assert_eq!(text.as_fixed_str().as_bytes().len(), 8);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let fs = FixedStr::<4>::new("abc");
+        let text = FixedText::from(fs);
+        assert_eq!(text, fs);
+        assert_eq!(text, "abc");
+        assert_eq!(FixedStr::from(text), fs);
+        assert_eq!("abcd".parse::<FixedText<4>>().unwrap(), "abcd");
+        assert!("abcde".parse::<FixedText<4>>().is_err());
+    }
+}
@@ -16,10 +16,19 @@
 //! - **String-first semantics:** The type treats the content as a genuine string rather than merely a raw byte array.
 //! - **Lossy by default:** Truncation prioritizes preserving valid UTF‑8 over preserving every byte.
 //! - **Strict by choice:** Methods like `TryFrom`, the builder (`FixedStrBuf`), and unsafe functions provide stricter control when needed.
-//! - **Const-ready:** Use [`FixedStr::new_const`] for compile-time construction, which performs silent truncation.
+//! - **Always valid:** A `FixedStr` always holds valid UTF‑8, so [`FixedStr::as_str`] never loses data.
+//!   Use [`RawFixedStr<N>`] for unvalidated, null‑padded bytes and convert with `TryFrom` once checked.
+//! - **Const-ready:** Use [`fixed_str!`] or [`FixedStr::from_literal`] for compile-time checked literals, or
+//!   [`FixedStr::new_const`] for compile-time construction with silent truncation.
 //!
 //! Also included:
 //! - [`FixedStrBuf<N>`]: A builder for incrementally constructing `FixedStr` values with boundary-aware methods such as `try_push_str()` and `push_str_lossy()`.
+//! - [`FixedStrStream<N>`]: Fills a `FixedStrBuf` from a byte stream, carrying characters split across reads.
+//! - [`PolicyStr<N, P>`]: A `FixedStr` whose overflow behavior ([`Truncate`], [`Reject`], [`Panic`], or [`Ellipsis`])
+//!   is part of its type and applies to every constructor, setter, and deserializer.
+//! - [`FixedText<N>`]: A `FixedStr` that dereferences to its effective `str`, so every `str` method is available.
+//! - [`FixedStrCi<N>`]: A case‑insensitive key that keeps its original text; look it up with [`CiStr`].
+//! - [`fixed_format!`] and [`fixed_format_lossy!`]: `format!`-style macros that render straight into a `FixedStr` without allocating.
 //! - Optional integrations for `serde`, `binrw`, and support for `no_std` environments.
 
 #![deny(missing_docs)]
@@ -28,7 +37,7 @@
 use core::{
     borrow::Borrow,
     cmp::Ordering,
-    fmt,
+    fmt::{self, Write as _},
     hash::{Hash, Hasher},
     str,
 };
@@ -43,22 +52,58 @@ use std::vec::Vec;
 pub mod effective_bytes;
 /// Provides the builder type `FixedStrBuf` for constructing fixed‑capacity strings.
 pub mod fs_buffer;
+/// Provides `FixedStrCi`, a case‑insensitive `FixedStr` key, and the `CiStr` lookup type.
+pub mod fs_ci;
 /// Contains the core implementation of the `FixedStr` type.
 pub mod fs_core;
+/// Provides in‑place editing for `FixedStrBuf`: insertion, removal, range replacement, draining,
+/// and checkpoint/rollback transactions.
+pub mod fs_edit;
 /// Defines custom error types for the `FixedStr` library.
 pub mod fs_error;
 /// Implements various trait implementations for `FixedStr`.
 pub mod fs_impl;
+/// Provides owned character and byte iterators for `FixedStr` and `FixedStrBuf`.
+pub mod fs_iter;
+/// Declares the `fixed_str!` literal macro and the `fixed_format!` family of macros.
+mod fs_macros;
+/// Provides `PolicyStr`, a `FixedStr` wrapper whose overflow behavior is set by a type parameter.
+pub mod fs_policy;
+/// Provides `RawFixedStr`, a null‑padded byte record without a UTF‑8 guarantee.
+pub mod fs_raw;
+/// Provides `Truncation`, the report returned by the `*_with_report` lossy operations.
+pub mod fs_report;
+/// Provides allocation‑free search, strip, and replace methods for `FixedStr`.
+pub mod fs_search;
+/// Provides `FixedStrStream`, which decodes byte streams into a `FixedStrBuf` incrementally,
+/// including `std::io::Write`.
+pub mod fs_stream;
+/// Provides `FixedText`, a `FixedStr` wrapper that dereferences to its effective `str`.
+pub mod fs_text;
 /// Provides optional integrations for binary and serialization support (`binrw` and `serde`).
 pub mod serialize_ext;
 /// Contains helper functions for byte copying, UTF‑8 boundary detection, and hex formatting.
 pub mod string_helpers;
 
 pub use effective_bytes::{EffectiveBytes, EffectiveBytesIter};
-pub use fs_buffer::FixedStrBuf;
+pub use fs_buffer::{FixedStrBuf, LossyWriter};
+pub use fs_ci::{AsciiFold, CaseFold, CiStr, FixedStrCi, SimpleFold};
 pub use fs_core::FixedStr;
+pub use fs_edit::{Drain, Mark};
 pub use fs_error::FixedStrError;
+pub use fs_iter::{CharIndices, Chars};
+pub use fs_policy::{
+    Ellipsis, InfalliblePolicy, OverflowPolicy, Panic, PolicyStr, Reject, Truncate,
+};
+pub use fs_raw::RawFixedStr;
+pub use fs_report::Truncation;
+pub use fs_search::FixedPattern;
+pub use fs_stream::FixedStrStream;
+pub use fs_text::FixedText;
+#[allow(deprecated)]
+pub use string_helpers::panic_on_zero;
+use string_helpers::{check_no_null, floor_char_boundary, AtLeastCapacity, NonZeroCapacity};
 pub use string_helpers::{
     copy_into_buffer, dump_as_hex, fast_format_hex, find_first_null, find_valid_boundary,
-    find_valid_utf8_len, panic_on_zero, truncate_utf8_lossy, BufferCopyMode,
+    find_valid_utf8_len, truncate_utf8_lossy, BufferCopyMode,
 };
@@ -6,14 +6,122 @@
 
 #[cfg(feature = "binrw")]
 mod binrw_ext {
+    extern crate alloc;
+
     use crate::*;
+    use alloc::boxed::Box;
     use binrw::io::{Read, Seek, Write};
     use binrw::{BinRead, BinWrite};
 
     /// Implements binary reading for `FixedStr` using the binrw crate.
+    ///
+    /// Reading fails with a custom error wrapping `FixedStrError::InvalidUtf8`
+    /// if the bytes before the first null are not valid UTF‑8. The error's
+    /// `valid_up_to` is relative to the start of the record at `pos`.
     impl<const N: usize> BinRead for FixedStr<N> {
         type Args<'a> = ();
 
+        fn read_options<R: Read + Seek>(
+            reader: &mut R,
+            endian: binrw::Endian,
+            args: Self::Args<'_>,
+        ) -> binrw::BinResult<Self> {
+            let pos = reader.stream_position()?;
+            let raw = RawFixedStr::<N>::read_options(reader, endian, args)?;
+            FixedStr::try_from(raw).map_err(|e| binrw::Error::Custom {
+                pos,
+                err: Box::new(e),
+            })
+        }
+    }
+
+    /// Implements binary reading for `PolicyStr` using the binrw crate.
+    ///
+    /// The record is exactly `N` bytes wide, so it always fits and is read like a `FixedStr`.
+    impl<const N: usize, P: OverflowPolicy> BinRead for PolicyStr<N, P> {
+        type Args<'a> = ();
+
+        fn read_options<R: Read + Seek>(
+            reader: &mut R,
+            endian: binrw::Endian,
+            args: Self::Args<'_>,
+        ) -> binrw::BinResult<Self> {
+            FixedStr::<N>::read_options(reader, endian, args).map(Self::from_fixed)
+        }
+    }
+
+    /// Implements binary writing for `PolicyStr` using the binrw crate.
+    impl<const N: usize, P: OverflowPolicy> BinWrite for PolicyStr<N, P> {
+        type Args<'a> = ();
+
+        fn write_options<W: Write + Seek>(
+            &self,
+            writer: &mut W,
+            endian: binrw::Endian,
+            args: Self::Args<'_>,
+        ) -> binrw::BinResult<()> {
+            self.as_fixed_str().write_options(writer, endian, args)
+        }
+    }
+
+    /// Implements binary reading for `FixedStrCi` using the binrw crate, like `FixedStr`.
+    impl<const N: usize, F: CaseFold> BinRead for FixedStrCi<N, F> {
+        type Args<'a> = ();
+
+        fn read_options<R: Read + Seek>(
+            reader: &mut R,
+            endian: binrw::Endian,
+            args: Self::Args<'_>,
+        ) -> binrw::BinResult<Self> {
+            FixedStr::<N>::read_options(reader, endian, args).map(Self::from_fixed)
+        }
+    }
+
+    /// Implements binary writing for `FixedStrCi` using the binrw crate, preserving the original case.
+    impl<const N: usize, F: CaseFold> BinWrite for FixedStrCi<N, F> {
+        type Args<'a> = ();
+
+        fn write_options<W: Write + Seek>(
+            &self,
+            writer: &mut W,
+            endian: binrw::Endian,
+            args: Self::Args<'_>,
+        ) -> binrw::BinResult<()> {
+            self.as_fixed_str().write_options(writer, endian, args)
+        }
+    }
+
+    /// Implements binary reading for `FixedText` using the binrw crate, like `FixedStr`.
+    impl<const N: usize> BinRead for FixedText<N> {
+        type Args<'a> = ();
+
+        fn read_options<R: Read + Seek>(
+            reader: &mut R,
+            endian: binrw::Endian,
+            args: Self::Args<'_>,
+        ) -> binrw::BinResult<Self> {
+            FixedStr::<N>::read_options(reader, endian, args).map(Self::from_fixed)
+        }
+    }
+
+    /// Implements binary writing for `FixedText` using the binrw crate, like `FixedStr`.
+    impl<const N: usize> BinWrite for FixedText<N> {
+        type Args<'a> = ();
+
+        fn write_options<W: Write + Seek>(
+            &self,
+            writer: &mut W,
+            endian: binrw::Endian,
+            args: Self::Args<'_>,
+        ) -> binrw::BinResult<()> {
+            self.as_fixed_str().write_options(writer, endian, args)
+        }
+    }
+
+    /// Implements binary reading for `RawFixedStr` using the binrw crate.
+    impl<const N: usize> BinRead for RawFixedStr<N> {
+        type Args<'a> = ();
+
         fn read_options<R: Read + Seek>(
             reader: &mut R,
             _endian: binrw::Endian,
@@ -21,7 +129,22 @@ mod binrw_ext {
         ) -> binrw::BinResult<Self> {
             let mut buf = [0u8; N];
             reader.read_exact(&mut buf)?;
-            Ok(Self { data: buf })
+            Ok(Self::from_bytes(buf))
+        }
+    }
+
+    /// Implements binary writing for `RawFixedStr` using the binrw crate.
+    impl<const N: usize> BinWrite for RawFixedStr<N> {
+        type Args<'a> = ();
+
+        fn write_options<W: Write + Seek>(
+            &self,
+            writer: &mut W,
+            _endian: binrw::Endian,
+            _args: Self::Args<'_>,
+        ) -> binrw::BinResult<()> {
+            writer.write_all(&self.data)?;
+            Ok(())
         }
     }
 
@@ -62,6 +185,28 @@ mod binrw_tests {
             FixedStr::read_options(&mut cursor, Endian::Little, ()).expect("reading failed");
         assert_eq!(original, read);
     }
+
+    #[test]
+    fn test_binrw_rejects_invalid_utf8() {
+        use binrw::{BinRead, Endian};
+        use std::io::Cursor;
+
+        let bytes = [b'o', b'k', 0xFF, 0];
+        let err = FixedStr::<4>::read_options(&mut Cursor::new(bytes), Endian::Little, ())
+            .expect_err("invalid UTF-8 must be rejected");
+        assert_eq!(
+            err.custom_err::<FixedStrError>(),
+            Some(&FixedStrError::InvalidUtf8 {
+                valid_up_to: 2,
+                error_len: Some(1),
+            })
+        );
+
+        // The same bytes are accepted as a raw record.
+        let raw = RawFixedStr::<4>::read_options(&mut Cursor::new(bytes), Endian::Little, ())
+            .expect("reading failed");
+        assert_eq!(raw, &bytes[..3]);
+    }
 }
 
 //******************************************************************************
@@ -72,6 +217,8 @@ mod binrw_tests {
 mod rkyv_ext {
     use crate::*;
     use rkyv::rancor::Fallible;
+    #[cfg(feature = "bytecheck")]
+    use rkyv::rancor::Source;
     use rkyv::ser::Writer;
     use rkyv::traits::CopyOptimization;
     use rkyv::{Archive, Deserialize, Portable, Serialize};
@@ -80,17 +227,79 @@ mod rkyv_ext {
     unsafe impl<const N: usize> Portable for FixedStr<N> {}
 
     /// Implements bytecheck validation for `FixedStr` (only with bytecheck feature).
+    ///
+    /// The bytes before the first null must be valid UTF‑8.
     #[cfg(feature = "bytecheck")]
     unsafe impl<const N: usize, C> rkyv::bytecheck::CheckBytes<C> for FixedStr<N>
+    where
+        C: rkyv::bytecheck::rancor::Fallible + rkyv::validation::ArchiveContext + ?Sized,
+        C::Error: Source,
+    {
+        unsafe fn check_bytes(value: *const Self, context: &mut C) -> Result<(), C::Error> {
+            // SAFETY: The caller guarantees that `value` points to `N` readable bytes, and
+            // FixedStr is a transparent wrapper around [u8; N].
+            let data = unsafe { &(*value).data };
+            let effective = data.effective_bytes();
+            let s =
+                core::ptr::slice_from_raw_parts(effective.as_ptr(), effective.len()) as *const str;
+            // SAFETY: `s` points into the same valid, aligned allocation as `value`.
+            unsafe { <str as rkyv::bytecheck::CheckBytes<C>>::check_bytes(s, context) }
+        }
+    }
+
+    /// Declares that `RawFixedStr` is portable across architectures.
+    unsafe impl<const N: usize> Portable for RawFixedStr<N> {}
+
+    /// Implements bytecheck validation for `RawFixedStr` (only with bytecheck feature).
+    #[cfg(feature = "bytecheck")]
+    unsafe impl<const N: usize, C> rkyv::bytecheck::CheckBytes<C> for RawFixedStr<N>
     where
         C: rkyv::bytecheck::rancor::Fallible + rkyv::validation::ArchiveContext + ?Sized,
     {
         unsafe fn check_bytes(_value: *const Self, _context: &mut C) -> Result<(), C::Error> {
-            // FixedStr is just a transparent wrapper around [u8; N], so it's always valid
+            // RawFixedStr is just a transparent wrapper around [u8; N], so it's always valid
             Ok(())
         }
     }
 
+    /// Implements rkyv archiving for `RawFixedStr`.
+    /// The archived form is `RawFixedStr` itself.
+    impl<const N: usize> Archive for RawFixedStr<N> {
+        type Archived = Self;
+        type Resolver = ();
+
+        /// Enables copy optimization for efficient serialization.
+        const COPY_OPTIMIZATION: CopyOptimization<Self> = unsafe { CopyOptimization::enable() };
+
+        #[inline]
+        fn resolve(&self, _resolver: Self::Resolver, out: rkyv::Place<Self::Archived>) {
+            // SAFETY: RawFixedStr is Copy and repr(transparent) around [u8; N]
+            unsafe {
+                core::ptr::write(out.ptr(), *self);
+            }
+        }
+    }
+
+    /// Implements rkyv serialization for `RawFixedStr`.
+    impl<const N: usize, S> Serialize<S> for RawFixedStr<N>
+    where
+        S: Fallible + Writer + ?Sized,
+    {
+        fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+            serializer.write(&self.data)
+        }
+    }
+
+    /// Implements rkyv deserialization for `RawFixedStr`.
+    impl<const N: usize, D> Deserialize<RawFixedStr<N>, D> for RawFixedStr<N>
+    where
+        D: Fallible + ?Sized,
+    {
+        fn deserialize(&self, _deserializer: &mut D) -> Result<RawFixedStr<N>, D::Error> {
+            Ok(*self)
+        }
+    }
+
     /// Implements rkyv archiving for `FixedStr`.
     /// The archived form is `FixedStr` itself.
     impl<const N: usize> Archive for FixedStr<N> {
@@ -104,7 +313,7 @@ mod rkyv_ext {
         fn resolve(&self, _resolver: Self::Resolver, out: rkyv::Place<Self::Archived>) {
             // SAFETY: FixedStr is Copy and repr(transparent) around [u8; N]
             unsafe {
-                core::ptr::write(out.ptr() as *mut Self, *self);
+                core::ptr::write(out.ptr(), *self);
             }
         }
     }
@@ -158,7 +367,7 @@ mod rkyv_tests {
         .expect("serialization failed");
 
         // Unsafe access without bytecheck validation.
-        let archived = unsafe { access_unchecked::<FixedStr<10>>(&*bytes) };
+        let archived = unsafe { access_unchecked::<FixedStr<10>>(&bytes) };
         assert_eq!(archived.as_str(), original.as_str());
     }
 
@@ -179,9 +388,23 @@ mod rkyv_tests {
         .expect("serialization failed");
 
         // Zero-copy access.
-        let archived = unsafe { access_unchecked::<FixedStr<8>>(&*bytes) };
+        let archived = unsafe { access_unchecked::<FixedStr<8>>(&bytes) };
         assert_eq!(archived.as_str(), "Test123");
     }
+
+    #[cfg(feature = "bytecheck")]
+    #[test]
+    fn test_rkyv_bytecheck_validates_utf8() {
+        use rkyv::api::low::access;
+
+        let valid = Align(*b"ok\0\0");
+        let archived = access::<FixedStr<4>, Failure>(&*valid).expect("valid UTF-8");
+        assert_eq!(archived.as_str(), "ok");
+
+        let invalid = Align([b'o', b'k', 0xFF, 0]);
+        assert!(access::<FixedStr<4>, Failure>(&*invalid).is_err());
+        assert!(access::<RawFixedStr<4>, Failure>(&*invalid).is_ok());
+    }
 }
 
 //******************************************************************************
@@ -193,7 +416,6 @@ mod serde_ext {
     use crate::*;
     use core::fmt;
     use serde::de::{Error as DeError, Visitor};
-    use serde::ser::Error as SerError;
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
     /// Implements Serde serialization for `FixedStr`.
@@ -202,10 +424,7 @@ mod serde_ext {
         where
             S: Serializer,
         {
-            match self.try_as_str() {
-                Ok(s) => serializer.serialize_str(s),
-                Err(_) => Err(S::Error::custom(FixedStrError::InvalidUtf8)),
-            }
+            serializer.serialize_str(self.as_str())
         }
     }
 
@@ -236,6 +455,123 @@ mod serde_ext {
             deserializer.deserialize_str(FixedStrVisitor::<N>)
         }
     }
+
+    /// Implements Serde serialization for `PolicyStr` as a string.
+    impl<const N: usize, P: OverflowPolicy> Serialize for PolicyStr<N, P> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    /// A visitor for deserializing a `PolicyStr`.
+    struct PolicyStrVisitor<const N: usize, P>(core::marker::PhantomData<P>);
+
+    impl<const N: usize, P: OverflowPolicy> Visitor<'_> for PolicyStrVisitor<N, P> {
+        type Value = PolicyStr<N, P>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a string of at most {} bytes", N)
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: DeError,
+        {
+            PolicyStr::try_new(value).map_err(E::custom)
+        }
+    }
+
+    /// Implements Serde deserialization for `PolicyStr`, applying the overflow policy `P`.
+    impl<'de, const N: usize, P: OverflowPolicy> Deserialize<'de> for PolicyStr<N, P> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(PolicyStrVisitor::<N, P>(core::marker::PhantomData))
+        }
+    }
+
+    /// Implements Serde serialization for `FixedStrCi` as a string, preserving the original case.
+    impl<const N: usize, F: CaseFold> Serialize for FixedStrCi<N, F> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    /// Implements Serde deserialization for `FixedStrCi` with the same rules as `FixedStr`.
+    impl<'de, const N: usize, F: CaseFold> Deserialize<'de> for FixedStrCi<N, F> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            FixedStr::<N>::deserialize(deserializer).map(Self::from_fixed)
+        }
+    }
+
+    /// Implements Serde serialization for `FixedText` as a string, like `FixedStr`.
+    impl<const N: usize> Serialize for FixedText<N> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            self.as_fixed_str().serialize(serializer)
+        }
+    }
+
+    /// Implements Serde deserialization for `FixedText` with the same rules as `FixedStr`.
+    impl<'de, const N: usize> Deserialize<'de> for FixedText<N> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            FixedStr::<N>::deserialize(deserializer).map(Self::from_fixed)
+        }
+    }
+
+    /// Implements Serde serialization for `RawFixedStr` as the full byte buffer.
+    impl<const N: usize> Serialize for RawFixedStr<N> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_bytes(self.as_bytes())
+        }
+    }
+
+    /// A visitor for deserializing a `RawFixedStr` from bytes.
+    struct RawFixedStrVisitor<const N: usize>;
+
+    impl<const N: usize> Visitor<'_> for RawFixedStrVisitor<N> {
+        type Value = RawFixedStr<N>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "at most {} bytes", N)
+        }
+
+        fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+        where
+            E: DeError,
+        {
+            let buf = copy_into_buffer(value, BufferCopyMode::Exact).map_err(E::custom)?;
+            Ok(RawFixedStr::from_bytes(buf))
+        }
+    }
+
+    /// Implements Serde deserialization for `RawFixedStr` from bytes, without UTF‑8 validation.
+    impl<'de, const N: usize> Deserialize<'de> for RawFixedStr<N> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_bytes(RawFixedStrVisitor::<N>)
+        }
+    }
 }
 
 /// Provides alternative (byte-based) serialization for `FixedStr` via Serde.
@@ -270,7 +606,7 @@ pub mod serde_as_bytes {
 mod serde_tests {
     use crate::*;
     use serde::{Deserialize, Serialize};
-    use serde_test::{assert_tokens, Token};
+    use serde_test::{assert_de_tokens, assert_tokens, Token};
 
     /// A test structure to verify byte-based serialization of FixedStr.
     #[derive(Serialize, Deserialize, Debug, PartialEq)]
@@ -279,6 +615,40 @@ mod serde_tests {
         inner: FixedStr<5>,
     }
 
+    #[test]
+    fn test_serde_policy_str() {
+        use serde_test::assert_de_tokens_error;
+
+        let s = PolicyStr::<5, Reject>::try_new("Hello").unwrap();
+        assert_tokens(&s, &[Token::Str("Hello")]);
+        assert_de_tokens_error::<PolicyStr<5, Reject>>(
+            &[Token::Str("Hello!")],
+            "Overflow: tried to add 6 bytes with only 5 bytes available",
+        );
+        assert_de_tokens(
+            &PolicyStr::<5, Ellipsis>::new("He..."),
+            &[Token::Str("Hello!")],
+        );
+    }
+
+    #[test]
+    fn test_serde_fixed_str_ci() {
+        let key = FixedStrCi::<8>::new("Host");
+        assert_tokens(&key, &[Token::Str("Host")]);
+        assert_de_tokens(&FixedStrCi::<8>::new("HOST"), &[Token::Str("host")]);
+    }
+
+    #[test]
+    fn test_serde_fixed_text() {
+        assert_tokens(&FixedText::<8>::new("Host"), &[Token::Str("Host")]);
+    }
+
+    #[test]
+    fn test_serde_raw_fixed_str() {
+        let raw = RawFixedStr::<4>::from_bytes([b'o', b'k', 0xFF, 0]);
+        assert_tokens(&raw, &[Token::BorrowedBytes(&[b'o', b'k', 0xFF, 0])]);
+    }
+
     #[test]
     fn test_serde_as_bytes() {
         let wrapper = ByteWrapper {
@@ -16,12 +16,38 @@ pub enum BufferCopyMode {
 
 /// Ensures that the provided capacity is greater than zero.
 ///
+/// The crate's own types no longer call this; they reject `N == 0` at compile time instead.
+///
 /// # Panics
 /// Panics if `n == 0`, since zero‑length strings are not supported.
+#[deprecated(
+    note = "zero capacities are now rejected at compile time; this check is no longer needed"
+)]
 pub const fn panic_on_zero(n: usize) {
     assert!(n > 0, "FixedStr capacity N must be greater than zero");
 }
 
+/// Compile‑time guard against zero‑capacity strings.
+///
+/// Every constructor evaluates [`NonZeroCapacity::ASSERT`], so instantiating one with `N == 0`
+/// fails during monomorphization rather than panicking at runtime.
+pub(crate) struct NonZeroCapacity<const N: usize>;
+
+impl<const N: usize> NonZeroCapacity<N> {
+    /// Fails const evaluation if `N == 0`.
+    pub(crate) const ASSERT: () = assert!(N > 0, "FixedStr capacity N must be greater than zero");
+}
+
+/// Compile‑time guard for conversions that must not lose content.
+///
+/// [`AtLeastCapacity::ASSERT`] fails during monomorphization unless `M >= N`.
+pub(crate) struct AtLeastCapacity<const N: usize, const M: usize>;
+
+impl<const N: usize, const M: usize> AtLeastCapacity<N, M> {
+    /// Fails const evaluation if `M < N`.
+    pub(crate) const ASSERT: () = assert!(M >= N, "target capacity M must be at least N");
+}
+
 /// Finds the index of the first null byte (`\0`) in the given slice.
 ///
 /// Returns the index of the first null byte, or the full length of the slice if no null is found.
@@ -36,11 +62,21 @@ pub fn find_first_null(bytes: &[u8]) -> usize {
     }
 }
 
+/// Returns an error if the input contains a null byte (`\0`).
+///
+/// Used by strict conversions, where a null byte would otherwise silently end the string.
+pub(crate) fn check_no_null(s: &str) -> Result<(), crate::FixedStrError> {
+    let position = find_first_null(s.as_bytes());
+    if position < s.len() {
+        Err(crate::FixedStrError::EmbeddedNull { position })
+    } else {
+        Ok(())
+    }
+}
+
 /// Finds the largest index (up to `max_len` and not exceeding the first null) such that
 /// the slice `bytes[..index]` is valid UTF‑8.
 ///
-/// This implementation uses a binary search approach for efficiency.
-///
 /// # Parameters
 /// - `bytes`: The input byte slice.
 /// - `max_len`: The maximum number of bytes to consider.
@@ -51,23 +87,26 @@ pub fn find_valid_utf8_len(bytes: &[u8], max_len: usize) -> usize {
     // Only consider bytes up to the first null (if any)
     let effective = find_first_null(bytes);
     let upper = max_len.min(effective);
-    // If the entire prefix is valid UTF‑8, return it.
-    if core::str::from_utf8(&bytes[..upper]).is_ok() {
-        return upper;
+    // Validity is not monotonic in the prefix length, so take the longest valid prefix
+    // reported by the decoder rather than searching for it.
+    match core::str::from_utf8(&bytes[..upper]) {
+        Ok(_) => upper,
+        Err(e) => e.valid_up_to(),
     }
-    // Otherwise, perform a binary search on the interval [0, upper] to find the largest valid prefix.
-    let mut low = 0;
-    let mut high = upper;
-    while low < high {
-        // Bias the midpoint upward to converge on the maximum valid index.
-        let mid = (low + high + 1) / 2;
-        if core::str::from_utf8(&bytes[..mid]).is_ok() {
-            low = mid;
-        } else {
-            high = mid - 1;
-        }
+}
+
+/// Returns the largest index `<= index` that does not fall inside a UTF‑8 continuation sequence.
+///
+/// If `index` is at or past the end of `bytes`, `bytes.len()` is returned.
+pub(crate) fn floor_char_boundary(bytes: &[u8], index: usize) -> usize {
+    if index >= bytes.len() {
+        return bytes.len();
+    }
+    let mut i = index;
+    while i > 0 && (bytes[i] & 0xC0) == 0x80 {
+        i -= 1;
     }
-    low
+    i
 }
 
 /// Truncates a byte slice to a valid UTF‑8 string within a specified maximum length.
@@ -140,13 +179,12 @@ pub const fn find_valid_boundary(bytes: &[u8], max_len: usize) -> usize {
 /// - `Slice`: Copies up to `N` bytes from the source, regardless of UTF‑8 validity.
 /// - `Truncate`: Copies as many valid UTF‑8 bytes as possible (up to `N`), truncating the source safely.
 ///
-/// # Panics
-/// Panics if `N == 0` (zero‑length strings are not supported).
+/// Instantiating this function with `N == 0` is a compile‑time error.
 pub fn copy_into_buffer<const N: usize>(
     src: &[u8],
     mode: BufferCopyMode,
 ) -> Result<[u8; N], crate::FixedStrError> {
-    panic_on_zero(N);
+    let () = NonZeroCapacity::<N>::ASSERT;
     let len = match mode {
         BufferCopyMode::Exact => {
             if src.len() > N {
@@ -334,6 +372,15 @@ mod helper_tests {
         assert_eq!(truncated, "d");
     }
 
+    #[test]
+    fn test_find_valid_utf8_len_multibyte() {
+        // Every 3-byte character boundary is valid, but most byte offsets are not.
+        let s = "日本語テキスト";
+        assert_eq!(find_valid_utf8_len(s.as_bytes(), 16), 15);
+        assert_eq!(find_valid_utf8_len(s.as_bytes(), 2), 0);
+        assert_eq!(find_valid_utf8_len(b"ab\xFFcd", 5), 2);
+    }
+
     #[test]
     fn test_exact_success() {
         let src = b"Hello";
@@ -380,13 +427,6 @@ mod helper_tests {
         assert_eq!(hex, "FF FF FF\nFF FF FF");
     }
 
-    #[test]
-    #[should_panic]
-    fn test_panic_on_zero() {
-        // This should panic because the capacity is zero.
-        let _ = crate::FixedStr::<0>::new("test");
-    }
-
     #[test]
     fn test_buffer_copy_mode_slice() {
         let input = b"Hello, world!";
@@ -48,6 +48,30 @@ mod fs_tests {
         assert_eq!(fixed.as_str(), "a");
     }
 
+    // Checks that the *_with_report constructors and setters describe what was dropped.
+    #[test]
+    fn test_truncation_reports() {
+        let (fixed, report) = FixedStr::<4>::new_with_report("a😊b");
+        assert_eq!(fixed.as_str(), "a");
+        assert_eq!(report.dropped_bytes, 5);
+        assert_eq!(report.dropped_chars, 2);
+        assert!(report.incomplete_utf8);
+        assert!(!report.stopped_at_nul);
+
+        let (fixed, report) = FixedStr::<8>::from_slice_with_report(b"id\0\0\0\0\0\0");
+        assert_eq!(fixed.as_str(), "id");
+        assert!(report.stopped_at_nul);
+        assert!(!report.is_truncated());
+
+        let mut fixed = FixedStr::<5>::new("");
+        let report = fixed.set_lossy_with_report("Hello");
+        assert_eq!(report, Truncation::default());
+        let report = fixed.set_lossy_with_report("Hello, World");
+        assert_eq!(fixed.as_str(), "Hello");
+        assert_eq!(report.dropped_bytes, 7);
+        assert!(!report.incomplete_utf8);
+    }
+
     // Checks that new_const produces a valid FixedStr at compile time for valid input.
     #[test]
     fn test_new_const_valid() {
@@ -71,7 +95,8 @@ mod fs_tests {
     fn test_from_slice() {
         const N: usize = 5;
         let slice = b"Hello, world!";
-        let fixed = FixedStr::<N>::from_slice_unsafe(slice);
+        // SAFETY: The input is ASCII.
+        let fixed = unsafe { FixedStr::<N>::from_slice_unsafe(slice) };
         // Expect the first 5 bytes ("Hello") to be used.
         assert_eq!(fixed.as_str(), "Hello");
     }
@@ -172,7 +197,8 @@ mod fs_tests {
     #[test]
     fn test_zero_termination() {
         let bytes = *b"Hello\0World";
-        let fixed = FixedStr::<11>::from_slice_unsafe(&bytes);
+        // SAFETY: The input is ASCII.
+        let fixed = unsafe { FixedStr::<11>::from_slice_unsafe(&bytes) };
         assert_eq!(fixed.len(), 5);
         assert_eq!(fixed.as_str(), "Hello");
     }
@@ -196,7 +222,8 @@ mod fs_tests {
     #[test]
     fn test_from_bytes_unsafe() {
         let bytes = *b"Raw!\0\0";
-        let fixed = FixedStr::<6>::from_bytes_unsafe(bytes);
+        // SAFETY: The input is ASCII.
+        let fixed = unsafe { FixedStr::<6>::from_bytes_unsafe(bytes) };
         assert_eq!(fixed.as_str(), "Raw!");
     }
 
@@ -226,7 +253,8 @@ mod fs_tests {
     #[test]
     fn test_as_mut_bytes() {
         let mut fixed = FixedStr::<4>::new("rust");
-        let bytes = fixed.as_mut_bytes();
+        // SAFETY: Replacing one ASCII byte with another keeps the content valid UTF‑8.
+        let bytes = unsafe { fixed.as_mut_bytes() };
         bytes[0] = b'R';
         assert_eq!(fixed.as_str(), "Rust");
     }
@@ -262,6 +290,122 @@ mod fs_tests {
         assert_eq!(s.as_str(), "Hi");
     }
 
+    // Ensures that truncate() never leaves a dangling lead byte of a multi-byte character.
+    #[test]
+    fn test_truncate_respects_char_boundary() {
+        let mut s = FixedStr::<8>::new("héllo");
+        s.truncate(2);
+        assert_eq!(s.as_str(), "h");
+        assert!(s.try_as_str().is_ok());
+        assert_eq!(s.as_bytes()[1..], [0u8; 7]);
+    }
+
+    // Ensures that try_truncate() reports a split character instead of rounding down.
+    #[test]
+    fn test_try_truncate_reports_split_char() {
+        let mut s = FixedStr::<8>::new("héllo");
+        assert_eq!(
+            s.try_truncate(2),
+            Err(FixedStrError::Truncated { dropped: 5 })
+        );
+        assert_eq!(s.as_str(), "héllo");
+        assert_eq!(s.try_truncate(1), Ok(()));
+        assert_eq!(s.as_str(), "h");
+    }
+
+    // Checks that truncate_chars() counts characters rather than bytes.
+    #[test]
+    fn test_truncate_chars() {
+        let mut s = FixedStr::<16>::new("日本語テキスト");
+        s.truncate_chars(3);
+        assert_eq!(s.as_str(), "日本語");
+        s.truncate_chars(10);
+        assert_eq!(s.as_str(), "日本語");
+    }
+
+    // Verifies resizing between capacities: lossy, strict, and widening.
+    #[test]
+    fn test_resize_between_capacities() {
+        let s = FixedStr::<8>::new("añb😊");
+        assert_eq!(s.resize::<3>().as_str(), "añ");
+        assert_eq!(s.resize::<2>().as_str(), "a");
+        assert_eq!(
+            s.try_resize::<4>(),
+            Err(FixedStrError::Overflow {
+                available: 4,
+                found: 8
+            })
+        );
+        assert_eq!(s.try_resize::<8>().unwrap(), s);
+        let wide: FixedStr<16> = s.widen();
+        assert_eq!(wide.as_str(), "añb😊");
+        assert_eq!(wide.as_bytes()[8..], [0u8; 8]);
+    }
+
+    // Compares and orders FixedStr values of different capacities and against strings.
+    #[test]
+    fn test_cross_capacity_comparisons() {
+        let long = FixedStr::<32>::new("sensor");
+        let short = FixedStr::<16>::new("sensor");
+        assert!(long.eq_fixed(&short));
+        assert_eq!(
+            FixedStr::<8>::new("alpha").cmp_fixed(&FixedStr::<4>::new("beta")),
+            core::cmp::Ordering::Less
+        );
+        // Same-capacity comparisons still infer the right-hand capacity.
+        assert_eq!(long, FixedStr::new("sensor"));
+        assert!(long > *"alpha");
+        assert!(long < "tank");
+        assert!("zeta" > long);
+        #[cfg(feature = "std")]
+        {
+            let owned = String::from("sensors");
+            assert!(long < owned);
+            assert!(owned > long);
+        }
+    }
+
+    // Ensures that FixedText derefs to the effective string and FixedStr keeps the raw buffer.
+    #[test]
+    fn test_fixed_text_deref_to_str() {
+        let s = FixedText::<16>::new("key=value");
+        assert_eq!((*s).len(), s.len());
+        assert!(s.contains('='));
+        assert_eq!(s.split('=').nth(1), Some("value"));
+        assert_eq!(s.chars().count(), 9);
+        assert!(!s.contains('\0'));
+        assert_eq!(s.as_bytes(), b"key=value");
+
+        let fs = s.into_inner();
+        assert_eq!((*fs).len(), 16);
+        assert_eq!(fs.as_bytes().len(), 16);
+    }
+
+    // Checks Unicode case conversion, including mappings that change the byte length.
+    #[test]
+    fn test_case_conversion() {
+        let fs = FixedStr::<8>::new("Grüße");
+        assert_eq!(fs.to_uppercase(Reject).unwrap(), "GRÜSSE");
+        assert_eq!(fs.to_lowercase(Reject).unwrap(), "grüße");
+        assert_eq!(fs.to_ascii_uppercase(), "GRüßE");
+        assert_eq!(fs.to_ascii_lowercase(), "grüße");
+
+        // 'İ' (2 bytes) lower-cases to "i̇" (3 bytes).
+        let fs = FixedStr::<4>::new("İİ");
+        assert_eq!(
+            fs.to_lowercase(Reject),
+            Err(FixedStrError::Overflow {
+                available: 4,
+                found: 6,
+            })
+        );
+        assert_eq!(fs.to_lowercase(Truncate).unwrap(), "i\u{307}i");
+        assert_eq!(
+            fs.to_lowercase_into::<6, _>(Reject).unwrap(),
+            "i\u{307}i\u{307}"
+        );
+    }
+
     // Tests conversion of FixedStr into an owned String.
     #[cfg(feature = "std")]
     #[test]
@@ -302,4 +446,36 @@ mod fs_tests {
         let fixed: FixedStr<5> = unsafe { transmute(arr) };
         assert_eq!(fixed.as_str(), "Hey");
     }
+
+    // Verifies that a HashMap keyed by FixedStr can be queried with &str, String and FixedStrBuf.
+    #[test]
+    fn test_hash_map_lookup_by_str() {
+        use std::collections::HashMap;
+        let mut map: HashMap<FixedStr<16>, u32> = HashMap::new();
+        map.insert(FixedStr::new("alpha"), 1);
+        map.insert(FixedStr::new("beta"), 2);
+
+        assert_eq!(map.get("alpha"), Some(&1));
+        assert_eq!(map.get(String::from("beta").as_str()), Some(&2));
+        assert_eq!(map.get("gamma"), None);
+
+        let mut buf = FixedStrBuf::<8>::new();
+        buf.try_push_str("beta").unwrap();
+        assert_eq!(map.get(buf.as_str()), Some(&2));
+    }
+
+    // Verifies that HashSets of FixedStr and FixedStrBuf can be queried by &str.
+    #[test]
+    fn test_hash_set_lookup_by_str() {
+        use std::collections::HashSet;
+        let set: HashSet<FixedStr<8>> = ["one", "two"].iter().map(|s| FixedStr::new(s)).collect();
+        assert!(set.contains("one"));
+        assert!(!set.contains("three"));
+
+        let mut buf = FixedStrBuf::<8>::new();
+        buf.try_push_str("two").unwrap();
+        let bufs: HashSet<FixedStrBuf<8>> = [buf].into_iter().collect();
+        assert!(bufs.contains("two"));
+        assert!(bufs.contains(&String::from("two")[..]));
+    }
 }